@@ -0,0 +1,33 @@
+fn main() {
+    juniper_from_schema_build::configure_for_schema_literal(
+        r#"
+        schema {
+            query: Query
+        }
+
+        type Query {
+            ping: Boolean!
+        }
+    "#,
+    )
+    .name("public")
+    .compile()
+    .unwrap();
+
+    juniper_from_schema_build::configure_for_schema_literal(
+        r#"
+        schema {
+            query: AdminQuery
+        }
+
+        type AdminQuery {
+            adminPing: Boolean!
+        }
+    "#,
+    )
+    .name("admin")
+    .schema_type_name("AdminSchema")
+    .query_trails_module_name("admin_query_trails")
+    .compile()
+    .unwrap();
+}
@@ -0,0 +1,36 @@
+#![allow(unused_braces)]
+
+use juniper::{EmptyMutation, EmptySubscription, Executor, FieldResult};
+
+juniper_from_schema::include_schema!("public");
+juniper_from_schema::include_schema!("admin");
+
+#[derive(Debug)]
+pub struct Context;
+
+impl juniper::Context for Context {}
+
+#[derive(Debug)]
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_ping(&self, _: &Executor<Context>) -> FieldResult<&bool> {
+        todo!()
+    }
+}
+
+#[derive(Debug)]
+pub struct AdminQuery;
+
+impl AdminQueryFields for AdminQuery {
+    fn field_admin_ping(&self, _: &Executor<Context>) -> FieldResult<&bool> {
+        todo!()
+    }
+}
+
+pub fn schemas() -> (Schema, AdminSchema) {
+    (
+        Schema::new(Query, EmptyMutation::new(), EmptySubscription::new()),
+        AdminSchema::new(AdminQuery, EmptyMutation::new(), EmptySubscription::new()),
+    )
+}
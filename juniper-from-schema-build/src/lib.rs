@@ -37,6 +37,43 @@
 //!
 //! // the rest of your code...
 //! ```
+//!
+//! ## Compiling several schemas
+//!
+//! Each schema must be given a name with [`CodeGen::name`]. The generated code for each schema
+//! should be included in its own module, or given distinct names for the `Schema` type alias and
+//! the `query_trails` module if they share one.
+//!
+//! ```no_run
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     juniper_from_schema_build::configure_for_file("schemas/public.graphql")
+//!         .name("public")
+//!         .compile()?;
+//!
+//!     juniper_from_schema_build::configure_for_file("schemas/admin.graphql")
+//!         .name("admin")
+//!         .schema_type_name("AdminSchema")
+//!         .query_trails_module_name("admin_query_trails")
+//!         .compile()?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ```ignore
+//! juniper_from_schema::include_schema!("public");
+//! juniper_from_schema::include_schema!("admin");
+//! ```
+//!
+//! ## Config file
+//!
+//! Settings in `juniper-from-schema.toml` next to `Cargo.toml` are used for everything that
+//! isn't set on the [`CodeGen`] itself. Use [`CodeGen::config_file`] to read another file. See
+//! the docs for "juniper-from-schema" for the supported settings.
+//!
+//! [`CodeGen::name`]: struct.CodeGen.html#method.name
+//! [`CodeGen`]: struct.CodeGen.html
+//! [`CodeGen::config_file`]: struct.CodeGen.html#method.config_file
 
 #![deny(
     dead_code,
@@ -49,6 +86,7 @@
 #![recursion_limit = "256"]
 #![doc(html_root_url = "https://docs.rs/juniper-from-schema-build/0.5.2")]
 
+use juniper_from_schema_code_gen::{parse_derives, validate_affix, Acronyms, Config};
 use std::{
     env,
     error::Error,
@@ -69,6 +107,17 @@ pub fn configure_for_schema_literal(schema: &str) -> CodeGen {
         schema: SchemaLocation::Literal(schema.to_string()),
         context_type: None,
         error_type: None,
+        name: None,
+        schema_type_name: None,
+        query_trails_module_name: None,
+        visibility: None,
+        fields_trait_suffix: None,
+        field_method_prefix: None,
+        acronyms: None,
+        enum_derives: None,
+        input_object_derives: None,
+        scalar_derives: None,
+        config_file: None,
     }
 }
 
@@ -88,6 +137,17 @@ pub fn configure_for_file<P: AsRef<Path>>(path: P) -> CodeGen {
         schema: SchemaLocation::File(path),
         context_type: None,
         error_type: None,
+        name: None,
+        schema_type_name: None,
+        query_trails_module_name: None,
+        visibility: None,
+        fields_trait_suffix: None,
+        field_method_prefix: None,
+        acronyms: None,
+        enum_derives: None,
+        input_object_derives: None,
+        scalar_derives: None,
+        config_file: None,
     }
 }
 
@@ -97,6 +157,17 @@ pub struct CodeGen {
     schema: SchemaLocation,
     context_type: Option<Result<syn::Type, Box<dyn Error>>>,
     error_type: Option<Result<syn::Type, Box<dyn Error>>>,
+    name: Option<String>,
+    schema_type_name: Option<Result<syn::Ident, Box<dyn Error>>>,
+    query_trails_module_name: Option<Result<syn::Ident, Box<dyn Error>>>,
+    visibility: Option<Result<syn::Visibility, Box<dyn Error>>>,
+    fields_trait_suffix: Option<Result<String, Box<dyn Error>>>,
+    field_method_prefix: Option<Result<String, Box<dyn Error>>>,
+    acronyms: Option<Result<Acronyms, Box<dyn Error>>>,
+    enum_derives: Option<Result<Vec<syn::Path>, Box<dyn Error>>>,
+    input_object_derives: Option<Result<Vec<syn::Path>, Box<dyn Error>>>,
+    scalar_derives: Option<Result<Vec<syn::Path>, Box<dyn Error>>>,
+    config_file: Option<PathBuf>,
 }
 
 #[derive(Debug)]
@@ -126,10 +197,124 @@ impl CodeGen {
         self
     }
 
+    /// Give the compiled schema a name.
+    ///
+    /// This is required if you compile several schemas in the same "build.rs". Each name gets its
+    /// own output file which you include with `juniper_from_schema::include_schema!("name")`.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Set the name of the generated `Schema` type alias.
+    ///
+    /// Will be parsed to a Rust identifier using [`syn::parse_str`].
+    ///
+    /// [`syn::parse_str`]: https://docs.rs/syn/1.0.48/syn/fn.parse_str.html
+    pub fn schema_type_name(mut self, schema_type_name: &str) -> Self {
+        self.schema_type_name = Some(syn::parse_str(schema_type_name).map_err(From::from));
+        self
+    }
+
+    /// Set the name of the generated `query_trails` module.
+    ///
+    /// Will be parsed to a Rust identifier using [`syn::parse_str`].
+    ///
+    /// [`syn::parse_str`]: https://docs.rs/syn/1.0.48/syn/fn.parse_str.html
+    pub fn query_trails_module_name(mut self, query_trails_module_name: &str) -> Self {
+        self.query_trails_module_name =
+            Some(syn::parse_str(query_trails_module_name).map_err(From::from));
+        self
+    }
+
+    /// Set the visibility of the generated items, such as `"pub(crate)"`.
+    ///
+    /// Will be parsed to a Rust visibility using [`syn::parse_str`].
+    ///
+    /// [`syn::parse_str`]: https://docs.rs/syn/1.0.48/syn/fn.parse_str.html
+    pub fn visibility(mut self, visibility: &str) -> Self {
+        self.visibility = Some(syn::parse_str(visibility).map_err(From::from));
+        self
+    }
+
+    /// Set the suffix of the traits with the fields of each object. Defaults to `"Fields"`.
+    pub fn fields_trait_suffix(mut self, fields_trait_suffix: &str) -> Self {
+        self.fields_trait_suffix = Some(
+            validate_affix(fields_trait_suffix)
+                .map(|()| fields_trait_suffix.to_string())
+                .map_err(From::from),
+        );
+        self
+    }
+
+    /// Set the prefix of the trait methods that resolve fields. Defaults to `"field_"`.
+    pub fn field_method_prefix(mut self, field_method_prefix: &str) -> Self {
+        self.field_method_prefix = Some(
+            validate_affix(field_method_prefix)
+                .map(|()| field_method_prefix.to_string())
+                .map_err(From::from),
+        );
+        self
+    }
+
+    /// Set how acronyms in type names are handled. Either `"keep"`, the default, or
+    /// `"camel_case"`, which turns `URL` into `Url`.
+    pub fn acronyms(mut self, acronyms: &str) -> Self {
+        self.acronyms = Some(acronyms.parse().map_err(From::from));
+        self
+    }
+
+    /// Set derives for all generated enums, such as `"strum::EnumString"`. They're added to the
+    /// derives that are always generated, unless the enum has `@juniper(derive: "...")`.
+    pub fn enum_derives(mut self, enum_derives: &str) -> Self {
+        self.enum_derives = Some(parse_derives(enum_derives).map_err(From::from));
+        self
+    }
+
+    /// Set derives for all generated input objects, such as
+    /// `"serde::Serialize, serde::Deserialize"`. They're added to the derives that are always
+    /// generated, unless the input object has `@juniper(derive: "...")`.
+    pub fn input_object_derives(mut self, input_object_derives: &str) -> Self {
+        self.input_object_derives = Some(parse_derives(input_object_derives).map_err(From::from));
+        self
+    }
+
+    /// Set derives for all generated custom scalars. They're added to the derives that are always
+    /// generated, unless the scalar has `@juniper(derive: "...")`.
+    pub fn scalar_derives(mut self, scalar_derives: &str) -> Self {
+        self.scalar_derives = Some(parse_derives(scalar_derives).map_err(From::from));
+        self
+    }
+
+    /// Read settings from a config file other than `juniper-from-schema.toml`. The path is
+    /// relative to `CARGO_MANIFEST_DIR`.
+    ///
+    /// Settings given to the `CodeGen` directly take precedence over the config file.
+    pub fn config_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        let root = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+        self.config_file = Some(root.join(path));
+        self
+    }
+
     /// Compile the GraphQL schema.
     pub fn compile(self) -> Result<(), Box<dyn Error>> {
         let out_dir = env::var_os("OUT_DIR").unwrap();
-        let dest_path = Path::new(&out_dir).join("juniper_from_schema_graphql_schema.rs");
+        let file_name = match &self.name {
+            Some(name) => format!("juniper_from_schema_graphql_schema_{}.rs", name),
+            None => "juniper_from_schema_graphql_schema.rs".to_string(),
+        };
+        let dest_path = Path::new(&out_dir).join(file_name);
+
+        let (config_path, config) = match self.config_file {
+            Some(path) => {
+                let config = Config::from_file(&path)?;
+                (Some(path), config)
+            }
+            None => match Config::from_manifest_dir()? {
+                Some((path, config)) => (Some(path), config),
+                None => (None, Config::default()),
+            },
+        };
 
         let mut code_gen = match self.schema {
             SchemaLocation::File(path) => {
@@ -138,7 +323,8 @@ impl CodeGen {
             SchemaLocation::Literal(schema) => {
                 juniper_from_schema_code_gen::CodeGen::build_from_schema_literal(schema)
             }
-        };
+        }
+        .config(config);
 
         if let Some(context_type) = self.context_type {
             code_gen = code_gen.context_type(context_type?);
@@ -148,11 +334,50 @@ impl CodeGen {
             code_gen = code_gen.error_type(error_type?);
         }
 
+        if let Some(schema_type_name) = self.schema_type_name {
+            code_gen = code_gen.schema_type_name(schema_type_name?);
+        }
+
+        if let Some(query_trails_module_name) = self.query_trails_module_name {
+            code_gen = code_gen.query_trails_module_name(query_trails_module_name?);
+        }
+
+        if let Some(visibility) = self.visibility {
+            code_gen = code_gen.visibility(visibility?);
+        }
+
+        if let Some(fields_trait_suffix) = self.fields_trait_suffix {
+            code_gen = code_gen.fields_trait_suffix(fields_trait_suffix?);
+        }
+
+        if let Some(field_method_prefix) = self.field_method_prefix {
+            code_gen = code_gen.field_method_prefix(field_method_prefix?);
+        }
+
+        if let Some(acronyms) = self.acronyms {
+            code_gen = code_gen.acronyms(acronyms?);
+        }
+
+        if let Some(enum_derives) = self.enum_derives {
+            code_gen = code_gen.enum_derives(enum_derives?);
+        }
+
+        if let Some(input_object_derives) = self.input_object_derives {
+            code_gen = code_gen.input_object_derives(input_object_derives?);
+        }
+
+        if let Some(scalar_derives) = self.scalar_derives {
+            code_gen = code_gen.scalar_derives(scalar_derives?);
+        }
+
         let code = code_gen.finish().generate_code()?;
 
         fs::write(&dest_path, code.to_string())?;
 
         println!("cargo:rerun-if-changed=build.rs");
+        if let Some(config_path) = config_path {
+            println!("cargo:rerun-if-changed={}", config_path.display());
+        }
 
         Ok(())
     }
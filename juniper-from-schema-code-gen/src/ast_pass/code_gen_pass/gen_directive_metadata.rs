@@ -0,0 +1,279 @@
+use super::{CodeGenPass, RustDoc};
+use crate::ast_pass::{
+    error::ErrorKind,
+    schema_visitor::{visit_document, SchemaVisitor},
+    EmitError,
+};
+use graphql_parser::{query::Value, schema::*, Pos};
+use heck::ShoutySnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+struct DirectiveMetadataCodeGenPass<'pass, 'doc> {
+    pass: &'pass mut CodeGenPass<'doc>,
+    tokens: TokenStream,
+}
+
+impl<'doc> CodeGenPass<'doc> {
+    pub fn gen_directive_metadata(&mut self, doc: &'doc Document<'doc, &'doc str>) -> TokenStream {
+        // Schemas that don't declare any directives shouldn't get a bunch of empty constants
+        if !self.ast_data.has_custom_directives() {
+            return TokenStream::new();
+        }
+
+        let mut directive_metadata_pass = DirectiveMetadataCodeGenPass {
+            pass: self,
+            tokens: TokenStream::new(),
+        };
+        visit_document(&mut directive_metadata_pass, doc);
+        directive_metadata_pass.tokens
+    }
+}
+
+impl<'pass, 'doc> DirectiveMetadataCodeGenPass<'pass, 'doc> {
+    fn gen_type_directives(
+        &mut self,
+        type_name: &'doc str,
+        directives: &'doc [Directive<'doc, &'doc str>],
+    ) {
+        let const_name = format_ident!("{}_DIRECTIVES", type_name.to_shouty_snake_case());
+        let directives = self.gen_directives(directives);
+        let visibility = &self.pass.options.visibility;
+
+        let mut doc = RustDoc::new();
+        doc.push(format!("Custom directives applied to `{}`.", type_name));
+        doc.push("Generated by `juniper-from-schema`.");
+
+        self.tokens.extend(quote! {
+            #doc
+            #[allow(dead_code)]
+            #visibility const #const_name: &[juniper_from_schema::Directive] = &[#(#directives),*];
+        });
+    }
+
+    fn gen_members_directives(
+        &mut self,
+        type_name: &'doc str,
+        members: &str,
+        entries: Vec<TokenStream>,
+    ) {
+        let const_name = format_ident!(
+            "{}_{}_DIRECTIVES",
+            type_name.to_shouty_snake_case(),
+            members.to_shouty_snake_case()
+        );
+        let visibility = &self.pass.options.visibility;
+
+        let mut doc = RustDoc::new();
+        doc.push(format!(
+            "Custom directives applied to the {members} of `{type_name}`. Only {members} with \
+             custom directives are listed.",
+            members = members,
+            type_name = type_name,
+        ));
+        doc.push("Generated by `juniper-from-schema`.");
+
+        self.tokens.extend(quote! {
+            #doc
+            #[allow(dead_code)]
+            #visibility const #const_name: &[juniper_from_schema::FieldDirectives] = &[#(#entries),*];
+        });
+    }
+
+    fn gen_field_directives(
+        &mut self,
+        name: &'doc str,
+        directives: &'doc [Directive<'doc, &'doc str>],
+        arguments: &'doc [InputValue<'doc, &'doc str>],
+    ) -> Option<TokenStream> {
+        let directives = self.gen_directives(directives);
+
+        let arguments = arguments
+            .iter()
+            .filter_map(|arg| {
+                let arg_name = arg.name;
+                let directives = self.gen_directives(&arg.directives);
+                if directives.is_empty() {
+                    None
+                } else {
+                    Some(quote! {
+                        juniper_from_schema::ArgumentDirectives {
+                            name: #arg_name,
+                            directives: &[#(#directives),*],
+                        }
+                    })
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if directives.is_empty() && arguments.is_empty() {
+            return None;
+        }
+
+        Some(quote! {
+            juniper_from_schema::FieldDirectives {
+                name: #name,
+                directives: &[#(#directives),*],
+                arguments: &[#(#arguments),*],
+            }
+        })
+    }
+
+    fn gen_directives(
+        &mut self,
+        directives: &'doc [Directive<'doc, &'doc str>],
+    ) -> Vec<TokenStream> {
+        directives
+            .iter()
+            .filter_map(|directive| {
+                let definition = self
+                    .pass
+                    .ast_data
+                    .custom_directive_definition(directive.name)?;
+                Some(self.gen_directive(directive, definition))
+            })
+            .collect()
+    }
+
+    fn gen_directive(
+        &mut self,
+        directive: &'doc Directive<'doc, &'doc str>,
+        definition: &'doc DirectiveDefinition<'doc, &'doc str>,
+    ) -> TokenStream {
+        for (key, _) in &directive.arguments {
+            if !definition.arguments.iter().any(|arg| arg.name == *key) {
+                self.pass.emit_error(
+                    directive.position,
+                    ErrorKind::UnknownCustomDirectiveArgument {
+                        directive: directive.name.to_string(),
+                        argument: key.to_string(),
+                    },
+                );
+            }
+        }
+
+        // Arguments are listed in the order they're declared in, with omitted arguments falling
+        // back to their default value
+        let arguments = definition
+            .arguments
+            .iter()
+            .filter_map(|arg| {
+                let value = directive
+                    .arguments
+                    .iter()
+                    .find(|(key, _)| *key == arg.name)
+                    .map(|(_, value)| value)
+                    .or(arg.default_value.as_ref())?;
+                let value = self.gen_value(directive.position, value);
+                let name = arg.name;
+                Some(quote! { (#name, #value) })
+            })
+            .collect::<Vec<_>>();
+
+        let name = directive.name;
+        quote! {
+            juniper_from_schema::Directive {
+                name: #name,
+                arguments: &[#(#arguments),*],
+            }
+        }
+    }
+
+    fn gen_value(&mut self, pos: Pos, value: &'doc Value<'doc, &'doc str>) -> TokenStream {
+        match value {
+            Value::Null => quote! { juniper_from_schema::DirectiveValue::Null },
+            Value::Int(int) => {
+                let int = int.as_i64().unwrap_or_default();
+                quote! { juniper_from_schema::DirectiveValue::Int(#int) }
+            }
+            Value::Float(float) => quote! { juniper_from_schema::DirectiveValue::Float(#float) },
+            Value::String(string) => {
+                quote! { juniper_from_schema::DirectiveValue::String(#string) }
+            }
+            Value::Boolean(boolean) => {
+                quote! { juniper_from_schema::DirectiveValue::Boolean(#boolean) }
+            }
+            Value::Enum(name) => quote! { juniper_from_schema::DirectiveValue::Enum(#name) },
+            Value::List(values) => {
+                let values = values
+                    .iter()
+                    .map(|value| self.gen_value(pos, value))
+                    .collect::<Vec<_>>();
+                quote! { juniper_from_schema::DirectiveValue::List(&[#(#values),*]) }
+            }
+            Value::Object(map) => {
+                let entries = map
+                    .iter()
+                    .map(|(key, value)| {
+                        let value = self.gen_value(pos, value);
+                        quote! { (#key, #value) }
+                    })
+                    .collect::<Vec<_>>();
+                quote! { juniper_from_schema::DirectiveValue::Object(&[#(#entries),*]) }
+            }
+            Value::Variable(_) => {
+                self.pass
+                    .emit_error(pos, ErrorKind::VariableInCustomDirective);
+                quote! { juniper_from_schema::DirectiveValue::Null }
+            }
+        }
+    }
+}
+
+impl<'pass, 'doc> SchemaVisitor<'doc> for DirectiveMetadataCodeGenPass<'pass, 'doc> {
+    fn visit_scalar_type(&mut self, scalar: &'doc ScalarType<'doc, &'doc str>) {
+        self.gen_type_directives(scalar.name, &scalar.directives);
+    }
+
+    fn visit_object_type(&mut self, obj: &'doc ObjectType<'doc, &'doc str>) {
+        self.gen_type_directives(obj.name, &obj.directives);
+
+        let fields = obj
+            .fields
+            .iter()
+            .filter_map(|field| {
+                self.gen_field_directives(field.name, &field.directives, &field.arguments)
+            })
+            .collect();
+        self.gen_members_directives(obj.name, "fields", fields);
+    }
+
+    fn visit_interface_type(&mut self, interface: &'doc InterfaceType<'doc, &'doc str>) {
+        self.gen_type_directives(interface.name, &interface.directives);
+
+        let fields = interface
+            .fields
+            .iter()
+            .filter_map(|field| {
+                self.gen_field_directives(field.name, &field.directives, &field.arguments)
+            })
+            .collect();
+        self.gen_members_directives(interface.name, "fields", fields);
+    }
+
+    fn visit_union_type(&mut self, union: &'doc UnionType<'doc, &'doc str>) {
+        self.gen_type_directives(union.name, &union.directives);
+    }
+
+    fn visit_enum_type(&mut self, enum_type: &'doc EnumType<'doc, &'doc str>) {
+        self.gen_type_directives(enum_type.name, &enum_type.directives);
+
+        let values = enum_type
+            .values
+            .iter()
+            .filter_map(|value| self.gen_field_directives(value.name, &value.directives, &[]))
+            .collect();
+        self.gen_members_directives(enum_type.name, "values", values);
+    }
+
+    fn visit_input_object_type(&mut self, input_type: &'doc InputObjectType<'doc, &'doc str>) {
+        self.gen_type_directives(input_type.name, &input_type.directives);
+
+        let fields = input_type
+            .fields
+            .iter()
+            .filter_map(|field| self.gen_field_directives(field.name, &field.directives, &[]))
+            .collect();
+        self.gen_members_directives(input_type.name, "fields", fields);
+    }
+}
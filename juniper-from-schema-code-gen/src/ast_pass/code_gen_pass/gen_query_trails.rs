@@ -1,11 +1,13 @@
-use super::CodeGenPass;
+use super::{args_struct_name, input_field_missing_value, CodeGenPass, RustDoc, Type, TypeName};
 use crate::ast_pass::{
+    directive_parsing::{rust_name, Deprecation, FromDirective},
     error::ErrorKind,
+    rust_ident,
     schema_visitor::{visit_document, SchemaVisitor},
     type_name, EmitError, TypeKind,
 };
 use graphql_parser::schema::*;
-use heck::{CamelCase, MixedCase, SnakeCase};
+use heck::MixedCase;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use std::{
@@ -18,16 +20,19 @@ struct QueryTrailCodeGenPass<'pass, 'doc> {
     pass: &'pass mut CodeGenPass<'doc>,
     tokens: TokenStream,
     fields_map: HashMap<&'doc str, Vec<&'doc Field<'doc, &'doc str>>>,
+    visibility: TokenStream,
 }
 
 impl<'doc> CodeGenPass<'doc> {
     pub fn gen_query_trails(&mut self, doc: &'doc Document<'doc, &'doc str>) -> TokenStream {
         let fields_map = build_fields_map(doc);
+        let visibility = visibility_inside_module(&self.options.visibility);
 
         let mut query_trail_pass = QueryTrailCodeGenPass {
             pass: self,
             tokens: TokenStream::new(),
             fields_map,
+            visibility,
         };
         query_trail_pass.gen_query_trail();
         query_trail_pass.gen_from_default_scalar_value();
@@ -35,15 +40,18 @@ impl<'doc> CodeGenPass<'doc> {
         visit_document(&mut query_trail_pass, doc);
 
         let tokens = query_trail_pass.tokens;
+        let query_trails_module_name = &self.options.query_trails_module_name;
+        let visibility = &self.options.visibility;
 
+        // `Walked`, `NotWalked`, and `QueryTrail` are re-exported through the glob import so
+        // several schemas can be generated into the same module without conflicting imports
         quote! {
-            pub use juniper_from_schema::{Walked, NotWalked, QueryTrail};
-            pub use self::query_trails::*;
+            #visibility use self::#query_trails_module_name::*;
 
             /// `QueryTrail` extension traits specific to the GraphQL schema
             ///
             /// Generated by `juniper-from-schema`.
-            pub mod query_trails {
+            #visibility mod #query_trails_module_name {
                 #![allow(unused_imports, dead_code, missing_docs)]
 
                 use super::*;
@@ -56,8 +64,10 @@ impl<'doc> CodeGenPass<'doc> {
 
 impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
     fn gen_query_trail(&mut self) {
+        let visibility = &self.visibility;
+
         self.tokens.extend(quote! {
-            use juniper_from_schema::{Walked, NotWalked, QueryTrail};
+            #visibility use juniper_from_schema::{Walked, NotWalked, QueryTrail};
 
             /// Convert from one type of `QueryTrail` to another. Used for converting interface and
             /// union trails into concrete subtypes.
@@ -67,7 +77,7 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
             /// orphan instances.
             ///
             /// Generated by `juniper-from-schema`.
-            pub trait DowncastQueryTrail<'r, T> {
+            #visibility trait DowncastQueryTrail<'r, T> {
                 /// Perform the downcast.
                 ///
                 /// Generated by juniper-from-schema.
@@ -90,7 +100,8 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
         });
 
         let gen_impl = |to: &str, variant: &str| {
-            let to = format_ident!("{}", to);
+            let to_name = to.rsplit("::").next().unwrap_or(to);
+            let to = syn::parse_str::<syn::Type>(to).expect("invalid scalar type");
             let variant = format_ident!("{}", variant);
             quote! {
                 impl<'a, 'b> FromDefaultScalarValue<#to> for &'a &'b juniper_from_schema::juniper::DefaultScalarValue {
@@ -99,21 +110,21 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                             juniper_from_schema::juniper::DefaultScalarValue::#variant(x) => x.to_owned(),
                             other => {
                                 match other {
-                                    juniper_from_schema::juniper::DefaultScalarValue::Int(_) => panic!(
+                                    juniper_from_schema::juniper::DefaultScalarValue::Int(_) => std::panic!(
                                         "Failed converting scalar value. Expected `{}` got `Int`",
-                                        stringify!(#to),
+                                        #to_name,
                                     ),
-                                    juniper_from_schema::juniper::DefaultScalarValue::String(_) => panic!(
+                                    juniper_from_schema::juniper::DefaultScalarValue::String(_) => std::panic!(
                                         "Failed converting scalar value. Expected `{}` got `String`",
-                                        stringify!(#to),
+                                        #to_name,
                                     ),
-                                    juniper_from_schema::juniper::DefaultScalarValue::Float(_) => panic!(
+                                    juniper_from_schema::juniper::DefaultScalarValue::Float(_) => std::panic!(
                                         "Failed converting scalar value. Expected `{}` got `Float`",
-                                        stringify!(#to),
+                                        #to_name,
                                     ),
-                                    juniper_from_schema::juniper::DefaultScalarValue::Boolean(_) => panic!(
+                                    juniper_from_schema::juniper::DefaultScalarValue::Boolean(_) => std::panic!(
                                         "Failed converting scalar value. Expected `{}` got `Boolean`",
-                                        stringify!(#to),
+                                        #to_name,
                                     ),
                                 }
                             }
@@ -124,17 +135,18 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
         };
 
         self.tokens.extend(gen_impl("i32", "Int"));
-        self.tokens.extend(gen_impl("String", "String"));
+        self.tokens
+            .extend(gen_impl("std::string::String", "String"));
         self.tokens.extend(gen_impl("f64", "Float"));
         self.tokens.extend(gen_impl("bool", "Boolean"));
 
         self.tokens.extend(quote! {
-            impl<'a, 'b, T> FromDefaultScalarValue<Option<T>> for &'a &'b juniper_from_schema::juniper::DefaultScalarValue
+            impl<'a, 'b, T> FromDefaultScalarValue<std::option::Option<T>> for &'a &'b juniper_from_schema::juniper::DefaultScalarValue
             where
                 &'a &'b juniper_from_schema::juniper::DefaultScalarValue: FromDefaultScalarValue<T>,
             {
-                fn from(self) -> Option<T> {
-                    Some(self.from())
+                fn from(self) -> std::option::Option<T> {
+                    std::option::Option::Some(self.from())
                 }
             }
         });
@@ -154,7 +166,7 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
         });
 
         let gen_scalar_impl = |to: &str| {
-            let to = format_ident!("{}", to);
+            let to = syn::parse_str::<syn::Type>(to).expect("invalid scalar type");
             quote! {
                 impl<'a, 'b> FromLookAheadValue<#to>
                     for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
@@ -164,16 +176,16 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                             juniper_from_schema::juniper::LookAheadValue::Scalar(scalar) => {
                                 FromDefaultScalarValue::from(scalar)
                             },
-                            juniper_from_schema::juniper::LookAheadValue::Null => panic!(
+                            juniper_from_schema::juniper::LookAheadValue::Null => std::panic!(
                                 "Failed converting look ahead value. Expected scalar type got `null`",
                             ),
-                            juniper_from_schema::juniper::LookAheadValue::Enum(_) => panic!(
+                            juniper_from_schema::juniper::LookAheadValue::Enum(_) => std::panic!(
                                 "Failed converting look ahead value. Expected scalar type got `enum`",
                             ),
-                            juniper_from_schema::juniper::LookAheadValue::List(_) => panic!(
+                            juniper_from_schema::juniper::LookAheadValue::List(_) => std::panic!(
                                 "Failed converting look ahead value. Expected scalar type got `list`",
                             ),
-                            juniper_from_schema::juniper::LookAheadValue::Object(_) => panic!(
+                            juniper_from_schema::juniper::LookAheadValue::Object(_) => std::panic!(
                                 "Failed converting look ahead value. Expected scalar type got `object`",
                             ),
                         }
@@ -183,44 +195,57 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
         };
 
         self.tokens.extend(gen_scalar_impl("i32"));
-        self.tokens.extend(gen_scalar_impl("String"));
+        self.tokens.extend(gen_scalar_impl("std::string::String"));
         self.tokens.extend(gen_scalar_impl("f64"));
         self.tokens.extend(gen_scalar_impl("bool"));
 
         self.tokens.extend(quote! {
-            impl<'a, 'b, T> FromLookAheadValue<Option<T>>
+            impl<'a, 'b, T> FromLookAheadValue<std::option::Option<T>>
+                for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
+            where
+                &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>: FromLookAheadValue<T>,
+            {
+                fn from(self) -> std::option::Option<T> {
+                    match self {
+                        juniper_from_schema::juniper::LookAheadValue::Null => std::option::Option::None,
+                        other => std::option::Option::Some(other.from()),
+                    }
+                }
+            }
+
+            impl<'a, 'b, T> FromLookAheadValue<juniper_from_schema::juniper::Nullable<T>>
                 for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
             where
                 &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>: FromLookAheadValue<T>,
             {
-                fn from(self) -> Option<T> {
+                fn from(self) -> juniper_from_schema::juniper::Nullable<T> {
                     match self {
-                        juniper_from_schema::juniper::LookAheadValue::Null => None,
-                        other => Some(other.from()),
+                        juniper_from_schema::juniper::LookAheadValue::Null => juniper_from_schema::juniper::Nullable::ExplicitNull,
+                        other => juniper_from_schema::juniper::Nullable::Some(other.from()),
                     }
                 }
             }
 
-            impl<'a, 'b, T> FromLookAheadValue<Vec<T>>
+            impl<'a, 'b, T> FromLookAheadValue<std::vec::Vec<T>>
                 for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
             where
                 &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>: FromLookAheadValue<T>,
             {
-                fn from(self) -> Vec<T> {
+                fn from(self) -> std::vec::Vec<T> {
                     match self {
                         juniper_from_schema::juniper::LookAheadValue::List(values) => {
-                            values.iter().map(|value| value.from()).collect::<Vec<_>>()
+                            values.iter().map(|value| value.from()).collect::<std::vec::Vec<_>>()
                         },
-                        juniper_from_schema::juniper::LookAheadValue::Scalar(_) => panic!(
+                        juniper_from_schema::juniper::LookAheadValue::Scalar(_) => std::panic!(
                             "Failed converting look ahead value. Expected list type got `scalar`",
                         ),
-                        juniper_from_schema::juniper::LookAheadValue::Null => panic!(
+                        juniper_from_schema::juniper::LookAheadValue::Null => std::panic!(
                             "Failed converting look ahead value. Expected list type got `null`",
                         ),
-                        juniper_from_schema::juniper::LookAheadValue::Enum(_) => panic!(
+                        juniper_from_schema::juniper::LookAheadValue::Enum(_) => std::panic!(
                             "Failed converting look ahead value. Expected list type got `enum`",
                         ),
-                        juniper_from_schema::juniper::LookAheadValue::Object(_) => panic!(
+                        juniper_from_schema::juniper::LookAheadValue::Object(_) => std::panic!(
                             "Failed converting look ahead value. Expected list type got `object`",
                         ),
                     }
@@ -231,7 +256,7 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                 for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
             {
                 fn from(self) -> juniper_from_schema::juniper::ID {
-                    let s = FromLookAheadValue::<String>::from(self);
+                    let s = FromLookAheadValue::<std::string::String>::from(self);
                     juniper_from_schema::juniper::ID::new(s)
                 }
             }
@@ -243,10 +268,10 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                     for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
                 {
                     fn from(self) -> url::Url {
-                        let s = FromLookAheadValue::<String>::from(self);
+                        let s = FromLookAheadValue::<std::string::String>::from(self);
                         match url::Url::parse(&s) {
-                            Ok(url) => url,
-                            Err(e) => panic!("Error parsing URL: {}", e),
+                            std::result::Result::Ok(url) => url,
+                            std::result::Result::Err(e) => std::panic!("Error parsing URL: {}", e),
                         }
                     }
                 }
@@ -259,10 +284,10 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                     for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
                 {
                     fn from(self) -> uuid::Uuid {
-                        let s = FromLookAheadValue::<String>::from(self);
+                        let s = FromLookAheadValue::<std::string::String>::from(self);
                         match uuid::Uuid::parse_str(&s) {
-                            Ok(url) => url,
-                            Err(e) => panic!("Error parsing UUID: {}", e),
+                            std::result::Result::Ok(url) => url,
+                            std::result::Result::Err(e) => std::panic!("Error parsing UUID: {}", e),
                         }
                     }
                 }
@@ -275,11 +300,11 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                     for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
                 {
                     fn from(self) -> chrono::NaiveDate {
-                        let s = FromLookAheadValue::<String>::from(self);
+                        let s = FromLookAheadValue::<std::string::String>::from(self);
                         match chrono::NaiveDate::parse_from_str(&s, "%Y-%m-%d") {
-                            Ok(date) => date,
-                            Err(e) => {
-                                panic!(
+                            std::result::Result::Ok(date) => date,
+                            std::result::Result::Err(e) => {
+                                std::panic!(
                                     "Error parsing NaiveDate. Format used is `%Y-%m-%d`\n{}",
                                     e,
                                 )
@@ -296,12 +321,12 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                     for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
                 {
                     fn from(self) -> chrono::DateTime<chrono::Utc> {
-                        let s = FromLookAheadValue::<String>::from(self);
+                        let s = FromLookAheadValue::<std::string::String>::from(self);
                         let parsed = chrono::DateTime::parse_from_rfc3339(&s);
                         match parsed {
-                            Ok(date_time) => date_time.into(),
-                            Err(e) => {
-                                panic!(
+                            std::result::Result::Ok(date_time) => date_time.into(),
+                            std::result::Result::Err(e) => {
+                                std::panic!(
                                     "Error parsing DateTime. Format used is RFC 3339 (aka ISO 8601)\n{}",
                                     e,
                                 )
@@ -314,12 +339,12 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                     for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
                 {
                     fn from(self) -> chrono::NaiveDateTime {
-                        let s = FromLookAheadValue::<String>::from(self);
+                        let s = FromLookAheadValue::<std::string::String>::from(self);
                         let parsed = chrono::NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S");
                         match parsed {
-                            Ok(date_time) => date_time.into(),
-                            Err(e) => {
-                                panic!(
+                            std::result::Result::Ok(date_time) => date_time.into(),
+                            std::result::Result::Err(e) => {
+                                std::panic!(
                                     "Error parsing NaiveDateTime. Format used is `%Y-%m-%d %H:%M:%S`\n{}",
                                     e,
                                 )
@@ -332,9 +357,10 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
     }
 
     fn gen_field_walk_methods(&mut self, obj: InternalQueryTrailNode<'doc>) {
-        let name = obj.name();
-        let trait_name = format_ident!("QueryTrail{}Extensions", obj.name());
-        let args_trait_name = format_ident!("QueryTrail{}ArgumentsExtensions", obj.name());
+        let name = self.pass.rust_type_name(obj.name());
+        let ty = self.pass.query_trail_type_name(obj.name());
+        let trait_name = format_ident!("QueryTrail{}Extensions", name);
+        let args_trait_name = format_ident!("QueryTrail{}ArgumentsExtensions", name);
         let fields = obj.fields();
 
         let mut method_signatures = vec![];
@@ -361,34 +387,35 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
             argument_types.push(argument_type);
         }
 
+        let visibility = &self.visibility;
         self.tokens.extend(quote! {
             /// Extension trait for `QueryTrail` to inspect incoming queries.
-            pub trait #trait_name<'r, K> {
+            #visibility trait #trait_name<'r, K> {
                 #(#method_signatures)*
             }
 
-            impl<'r, K> #trait_name<'r, K> for QueryTrail<'r, #name, K> {
+            impl<'r, K> #trait_name<'r, K> for QueryTrail<'r, #ty, K> {
                 #(#method_implementations)*
             }
 
             /// Extension trait for `QueryTrail` to inspect incoming query arguments.
-            pub trait #args_trait_name<'r> {
+            #visibility trait #args_trait_name<'r> {
                 #(#argument_signatures)*
             }
 
-            impl<'r> #args_trait_name<'r> for QueryTrail<'r, #name, juniper_from_schema::Walked> {
+            impl<'r> #args_trait_name<'r> for QueryTrail<'r, #ty, juniper_from_schema::Walked> {
                 #(#argument_implementations)*
             }
 
             #(#argument_types)*
         });
 
-        self.gen_conversion_methods(name, obj);
+        self.gen_conversion_methods(ty, obj);
     }
 
     fn gen_conversion_methods(
         &mut self,
-        original_type_name: Ident,
+        original_type_name: TypeName,
         obj: InternalQueryTrailNode<'_>,
     ) {
         let mut destination_types = vec![];
@@ -398,14 +425,14 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
             InternalQueryTrailNode::Interface(i) => {
                 if let Some(i) = &self.pass.ast_data.get_implementors_of_interface(&i.name) {
                     for interface_implementor_name in *i {
-                        let ident = format_ident!("{}", interface_implementor_name);
+                        let ident = self.pass.query_trail_type_name(interface_implementor_name);
                         destination_types.push(ident);
                     }
                 }
             }
             InternalQueryTrailNode::Union(u, _) => {
                 for type_ in &u.types {
-                    let ident = format_ident!("{}", type_);
+                    let ident = self.pass.query_trail_type_name(type_);
                     destination_types.push(ident);
                 }
             }
@@ -457,6 +484,16 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
         }
     }
 
+    fn walk_method_name(&self, field: &'doc Field<'doc, &'doc str>) -> Ident {
+        rust_ident(
+            &self
+                .pass
+                .options
+                .naming
+                .field_name(field.name, rust_name(&field.directives)),
+        )
+    }
+
     fn gen_field_walk_method(
         &mut self,
         field: &'doc Field<'doc, &'doc str>,
@@ -466,17 +503,19 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
         let ty = self
             .pass
             .graphql_type_to_rust_type(&field.field_type, false, field.position);
-        let field_type = format_ident!("{}", field_type.to_camel_case());
+        let field_type = self.pass.query_trail_type_name(field_type);
 
         match ty.kind() {
             TypeKind::Scalar => {
-                let name = format_ident!("{}", &field.name.to_snake_case());
+                let name = self.walk_method_name(field);
                 let string_name = &field.name.to_mixed_case();
 
+                let mut doc = field_rust_doc(field);
+                doc.push("Check if a scalar leaf node is queried for");
+                doc.push("Generated by `juniper-from-schema`.");
+
                 let method_signature = quote! {
-                    /// Check if a scalar leaf node is queried for
-                    ///
-                    /// Generated by `juniper-from-schema`.
+                    #doc
                     fn #name(&self) -> bool;
                 };
 
@@ -506,13 +545,15 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                 }
             }
             TypeKind::Type => {
-                let name = format_ident!("{}", &field.name.to_snake_case());
+                let name = self.walk_method_name(field);
                 let string_name = &field.name.to_mixed_case();
 
+                let mut doc = field_rust_doc(field);
+                doc.push("Walk the trail into a field.");
+                doc.push("Generated by `juniper-from-schema`.");
+
                 let method_signature = quote! {
-                    /// Walk the trail into a field.
-                    ///
-                    /// Generated by `juniper-from-schema`.
+                    #doc
                     fn #name(&self) -> QueryTrail<'r, #field_type, juniper_from_schema::NotWalked>;
                 };
 
@@ -558,7 +599,7 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
         let mut argument_implementation = quote! {};
         let mut argument_type = quote! {};
 
-        let obj_type = obj.name();
+        let obj_type = self.pass.query_trail_type_name(obj.name());
 
         let args_method_name = format_ident!("{}_args", name);
 
@@ -579,9 +620,48 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                     ()
                 }
             });
+        } else if self.uses_args_struct(field, obj) {
+            // The struct the field's trait method takes is reused so there is only one type
+            // for the arguments
+            let args_type_name = args_struct_name(&obj_type.ident, name);
+            let field_name = &field.name;
+
+            let (idents, values): (Vec<_>, Vec<_>) = field
+                .arguments
+                .iter()
+                .map(|input_value| {
+                    let argument = self.argument_look_ahead(input_value, true);
+                    (argument.ident, argument.value)
+                })
+                .unzip();
+
+            argument_signature.extend(quote! {
+                /// Inspect arguments in incoming query.
+                fn #args_method_name(&self) -> #args_type_name;
+            });
+
+            argument_implementation.extend(quote! {
+                #[allow(missing_docs)]
+                fn #args_method_name(&self) -> #args_type_name {
+                    use juniper_from_schema::juniper::LookAheadMethods;
+
+                    // this `expect` is fine since arguments can only be obtained from walked
+                    // query trails
+                    let lh = &self
+                        .look_ahead
+                        .expect("look_ahead")
+                        .children()
+                        .into_iter()
+                        .find(|child| child.field_name() == #field_name)
+                        .expect("select child");
+
+                    #args_type_name {
+                        #(#idents: #values,)*
+                    }
+                }
+            });
         } else {
-            let args_type_name =
-                format_ident!("{}{}Args", obj.name(), name.to_string().to_camel_case());
+            let args_type_name = args_struct_name(&obj_type.ident, name);
 
             argument_signature.extend(quote! {
                 /// Inspect argument in incoming query.
@@ -598,13 +678,15 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
             let arguments_methods = field
                 .arguments
                 .iter()
-                .map(|input_value| self.gen_argument_look_ahead_methods(input_value, &field.name));
+                .map(|input_value| self.gen_argument_look_ahead_method(input_value, &field.name))
+                .collect::<Vec<_>>();
 
+            let visibility = &self.visibility;
             argument_type.extend(quote! {
                 /// This is used for inspecting arguments to a field.
                 ///
                 /// Generated by `juniper-from-schema`.
-                pub struct #args_type_name<'r>(
+                #visibility struct #args_type_name<'r>(
                     &'r QueryTrail<'r, #obj_type, juniper_from_schema::Walked>
                 );
 
@@ -617,11 +699,66 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
         (argument_signature, argument_implementation, argument_type)
     }
 
-    fn gen_argument_look_ahead_methods(
+    /// Only objects get args structs. Interfaces and unions keep the query trail wrapper since
+    /// there is no single struct their arguments could be read into.
+    fn uses_args_struct(
+        &self,
+        field: &'doc Field<'doc, &'doc str>,
+        obj: &InternalQueryTrailNode,
+    ) -> bool {
+        match obj {
+            InternalQueryTrailNode::Object(inner) => self
+                .pass
+                .ast_data
+                .uses_args_struct(inner.name, &field.directives),
+            InternalQueryTrailNode::Interface(_) | InternalQueryTrailNode::Union(..) => false,
+        }
+    }
+
+    fn gen_argument_look_ahead_method(
         &mut self,
         input_value: &'doc InputValue<'doc, &'doc str>,
         field_name: &str,
     ) -> TokenStream {
+        let ArgumentLookAhead {
+            ident,
+            ty,
+            doc,
+            value,
+        } = self.argument_look_ahead(input_value, false);
+
+        quote! {
+            #[allow(missing_docs)]
+            #doc
+            pub fn #ident(&self) -> #ty {
+                use juniper_from_schema::juniper::LookAheadMethods;
+
+                // these `expect`s are fine since these methods you can only obtain
+                // arguments from walked query trails
+                let lh = &self
+                    .0
+                    .look_ahead
+                    .expect("look_ahead")
+                    .children()
+                    .into_iter()
+                    .find(|child| child.field_name() == #field_name)
+                    .expect("select child");
+
+                #value
+            }
+        }
+    }
+
+    /// Reading an argument from the look ahead selection `lh` of its field.
+    ///
+    /// Tristate arguments left out of the query are read as `Nullable::ImplicitNull`. Args structs
+    /// have a field for every argument, so with `in_args_struct` other nullable arguments left out
+    /// are read as `None` rather than panicking.
+    fn argument_look_ahead(
+        &mut self,
+        input_value: &'doc InputValue<'doc, &'doc str>,
+        in_args_struct: bool,
+    ) -> ArgumentLookAhead {
         let default_value = input_value.default_value.as_ref().map(|value| {
             self.pass.quote_value(
                 &value,
@@ -630,74 +767,91 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
             )
         });
 
-        let mut field_type = &self.pass.graphql_type_to_rust_type(
-            &input_value.value_type,
-            false,
-            input_value.position,
-        );
+        let mut ty = self.pass.input_value_type(input_value);
 
         if default_value.is_some() {
-            field_type = field_type.remove_one_layer_of_nullability();
+            ty = *ty.remove_one_layer_of_nullability_by_value();
         }
 
         let name = &input_value.name;
-        let ident = format_ident!("{}", name.to_snake_case());
-
-        if let Some(default_value) = default_value {
-            quote! {
-                #[allow(missing_docs)]
-                pub fn #ident(&self) -> #field_type {
-                    use juniper_from_schema::juniper::LookAheadMethods;
+        let ident = rust_ident(
+            &self
+                .pass
+                .options
+                .naming
+                .field_name(name, rust_name(&input_value.directives)),
+        );
 
-                    // these `expect`s are fine since these methods you can only obtain
-                    // arguments from walked query trails
-                    let lh = &self
-                        .0
-                        .look_ahead
-                        .expect("look_ahead")
-                        .children()
-                        .into_iter()
-                        .find(|child| child.field_name() == #field_name)
-                        .expect("select child");
+        let deprecation = input_value
+            .directives
+            .iter()
+            .find_map(|dir| Deprecation::from_directive(dir).ok());
+
+        let mut doc = RustDoc::new();
+        doc.push_description(input_value.description.as_ref());
+        doc.push_deprecation(deprecation.as_ref());
+        doc.push_default_value(
+            input_value
+                .default_value
+                .as_ref()
+                .map(|value| value.to_string())
+                .as_ref(),
+        );
 
-                    let arg = lh.arguments().iter().find(|arg| {
-                        arg.name() == #name
-                    });
+        let value = if let Some(default_value) = default_value {
+            quote! {
+                let arg = lh.arguments().iter().find(|arg| {
+                    arg.name() == #name
+                });
 
-                    if let Some(arg) = arg {
-                        let value = arg.value();
-                        FromLookAheadValue::<#field_type>::from(value)
-                    } else {
-                        #default_value
-                    }
+                if let std::option::Option::Some(arg) = arg {
+                    let value = arg.value();
+                    FromLookAheadValue::<#ty>::from(value)
+                } else {
+                    #default_value
                 }
             }
-        } else {
+        } else if let Some(missing_value) = input_field_missing_value(&ty)
+            .filter(|_| in_args_struct || matches!(ty, Type::Tristate(_)))
+        {
+            // Arguments left out of the query aren't in the look ahead selection
             quote! {
-                #[allow(missing_docs)]
-                pub fn #ident(&self) -> #field_type {
-                    use juniper_from_schema::juniper::LookAheadMethods;
+                let arg = lh.arguments().iter().find(|arg| {
+                    arg.name() == #name
+                });
 
-                    // these `expect`s are fine since these methods you can only obtain
-                    // arguments from walked query trails
-                    let lh = &self
-                        .0
-                        .look_ahead
-                        .expect("look_ahead")
-                        .children()
-                        .into_iter()
-                        .find(|child| child.field_name() == #field_name)
-                        .expect("select child");
-
-                    let arg = lh.arguments().iter().find(|arg| { arg.name() == #name }).expect("no argument with name");
+                if let std::option::Option::Some(arg) = arg {
                     let value = arg.value();
-                    FromLookAheadValue::<#field_type>::from(value)
+                    FromLookAheadValue::<#ty>::from(value)
+                } else {
+                    #missing_value
                 }
             }
+        } else {
+            quote! {
+                let arg = lh.arguments().iter().find(|arg| { arg.name() == #name }).expect("no argument with name");
+                let value = arg.value();
+                FromLookAheadValue::<#ty>::from(value)
+            }
+        };
+
+        ArgumentLookAhead {
+            ident,
+            ty,
+            doc,
+            value: quote! { { #value } },
         }
     }
 }
 
+struct ArgumentLookAhead {
+    ident: Ident,
+    ty: Type,
+    doc: RustDoc,
+    /// A block evaluating to the argument's value.
+    value: TokenStream,
+}
+
 impl<'pass, 'doc> SchemaVisitor<'doc> for QueryTrailCodeGenPass<'pass, 'doc> {
     fn visit_object_type(&mut self, obj: &'doc ObjectType<'doc, &'doc str>) {
         self.gen_field_walk_methods(InternalQueryTrailNode::Object(obj));
@@ -750,11 +904,11 @@ enum InternalQueryTrailNode<'a> {
 }
 
 impl<'a> InternalQueryTrailNode<'a> {
-    fn name(&self) -> Ident {
+    fn name(&self) -> &'a str {
         match self {
-            InternalQueryTrailNode::Object(inner) => format_ident!("{}", inner.name),
-            InternalQueryTrailNode::Interface(inner) => format_ident!("{}", inner.name),
-            InternalQueryTrailNode::Union(inner, _fields) => format_ident!("{}", inner.name),
+            InternalQueryTrailNode::Object(inner) => inner.name,
+            InternalQueryTrailNode::Interface(inner) => inner.name,
+            InternalQueryTrailNode::Union(inner, _fields) => inner.name,
         }
     }
 
@@ -806,10 +960,48 @@ fn build_fields_map<'a>(
     map
 }
 
+/// Docs for a query trail method, taken from the description and deprecation of the field it
+/// walks into.
+fn field_rust_doc<'doc>(field: &'doc Field<'doc, &'doc str>) -> RustDoc {
+    let deprecation = field
+        .directives
+        .iter()
+        .find_map(|dir| Deprecation::from_directive(dir).ok());
+
+    let mut doc = RustDoc::new();
+    doc.push_description(field.description.as_ref());
+    doc.push_deprecation(deprecation.as_ref());
+    doc
+}
+
+/// The generated query trail items live one module below the rest of the generated code, so a
+/// restricted visibility has to be adjusted to keep pointing at the same module.
+fn visibility_inside_module(visibility: &syn::Visibility) -> TokenStream {
+    match visibility {
+        syn::Visibility::Public(_) => quote! { pub },
+        syn::Visibility::Crate(_) => quote! { pub(crate) },
+        syn::Visibility::Inherited => quote! { pub(super) },
+        syn::Visibility::Restricted(restricted) => {
+            let path = &restricted.path;
+            let first = path
+                .segments
+                .first()
+                .map(|segment| segment.ident.to_string());
+
+            match first.as_deref() {
+                Some("crate") => quote! { pub(in #path) },
+                Some("self") if path.segments.len() == 1 => quote! { pub(super) },
+                Some("super") => quote! { pub(in super::#path) },
+                _ => quote! { #visibility },
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::ast_pass::AstData;
+    use crate::ast_pass::{code_gen_pass::CodeGenOptions, AstData};
 
     #[test]
     fn test_fails_to_generate_query_trail_for_unions_where_fields_dont_overlap() {
@@ -835,9 +1027,16 @@ mod test {
 
         let doc = graphql_parser::parse_schema(&schema).unwrap();
         let ast_data = AstData::new_from_doc(&doc).unwrap();
-        let context_type = crate::default_error_type();
-        let error_type = crate::default_context_type();
-        let mut out = CodeGenPass::new(schema, &context_type, &error_type, ast_data);
+        let options = CodeGenOptions {
+            context_type: crate::default_context_type(),
+            error_type: crate::default_error_type(),
+            schema_type_name: crate::default_schema_type_name(),
+            query_trails_module_name: crate::default_query_trails_module_name(),
+            visibility: crate::default_visibility(),
+            naming: Default::default(),
+            derives: Default::default(),
+        };
+        let mut out = CodeGenPass::new(schema, None, &options, ast_data);
 
         out.gen_query_trails(&doc);
 
@@ -0,0 +1,236 @@
+use super::CodeGenPass;
+use crate::ast_pass::{
+    directive_parsing::{rust_name, FieldOfType, Ownership, ParseDirective},
+    naming::Naming,
+    schema_visitor::{visit_document, SchemaVisitor},
+};
+use graphql_parser::schema::*;
+use heck::ShoutySnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, LitStr};
+
+struct SchemaInfoCodeGenPass<'pass, 'doc> {
+    pass: &'pass mut CodeGenPass<'doc>,
+    types: Vec<TokenStream>,
+}
+
+impl<'doc> CodeGenPass<'doc> {
+    pub fn gen_schema_info(&mut self, doc: &'doc Document<'doc, &'doc str>) -> TokenStream {
+        let mut schema_info_pass = SchemaInfoCodeGenPass {
+            pass: self,
+            types: Vec::new(),
+        };
+        visit_document(&mut schema_info_pass, doc);
+        let types = schema_info_pass.types;
+
+        // Including the schema file also makes Cargo rebuild the Rust code when only the
+        // GraphQL schema changes
+        let sdl = match self.schema_path {
+            Some(path) => {
+                let path = LitStr::new(
+                    path.to_str()
+                        .expect("Invalid UTF-8 characters in file name"),
+                    proc_macro2::Span::call_site(),
+                );
+                quote! { std::include_str!(#path) }
+            }
+            None => {
+                let raw_schema = self.raw_schema;
+                quote! { #raw_schema }
+            }
+        };
+
+        let const_name = format_ident!(
+            "{}_INFO",
+            self.options
+                .schema_type_name
+                .to_string()
+                .to_shouty_snake_case()
+        );
+        let visibility = &self.options.visibility;
+
+        quote! {
+            /// The types and fields of the GraphQL schema along with the schema itself.
+            ///
+            /// Generated by `juniper-from-schema`.
+            #[allow(dead_code)]
+            #visibility const #const_name: juniper_from_schema::SchemaInfo =
+                juniper_from_schema::SchemaInfo {
+                    sdl: #sdl,
+                    types: &[#(#types),*],
+                };
+        }
+    }
+}
+
+impl<'pass, 'doc> SchemaInfoCodeGenPass<'pass, 'doc> {
+    fn gen_type_info(
+        &mut self,
+        name: &'doc str,
+        kind: TokenStream,
+        description: Option<&'doc String>,
+        fields: Vec<TokenStream>,
+        input_fields: Vec<TokenStream>,
+    ) {
+        let description = quote_option_str(description);
+
+        self.types.push(quote! {
+            juniper_from_schema::TypeInfo {
+                name: #name,
+                kind: juniper_from_schema::TypeKind::#kind,
+                description: #description,
+                fields: &[#(#fields),*],
+                input_fields: &[#(#input_fields),*],
+            }
+        });
+    }
+
+    fn gen_field_infos(
+        &mut self,
+        type_name: &'doc str,
+        fields: &'doc [Field<'doc, &'doc str>],
+    ) -> Vec<TokenStream> {
+        fields
+            .iter()
+            .map(|field| {
+                let directives = self.pass.parse_directives(FieldOfType(type_name, field));
+
+                let naming = &self.pass.options.naming;
+                let name = field.name;
+                let field_name = naming.field_name(field.name, rust_name(&field.directives));
+                let rust_name = naming
+                    .field_method_name(&format_ident!("{}", field_name))
+                    .unraw()
+                    .to_string();
+                let description = quote_option_str(field.description.as_ref());
+                let field_type = field.field_type.to_string();
+                let arguments = gen_input_value_infos(naming, &field.arguments);
+                let ownership = match directives.ownership {
+                    Ownership::Borrowed => quote! { Borrowed },
+                    Ownership::Owned => quote! { Owned },
+                    Ownership::AsRef => quote! { AsRef },
+                    Ownership::Arc => quote! { Arc },
+                    Ownership::Str => quote! { Str },
+                    Ownership::Cow => quote! { Cow },
+                    Ownership::Slice => quote! { Slice },
+                    Ownership::Iter => quote! { Iter },
+                };
+                let is_async = directives.r#async.value;
+                let infallible = directives.infallible.value;
+
+                quote! {
+                    juniper_from_schema::FieldInfo {
+                        name: #name,
+                        rust_name: #rust_name,
+                        description: #description,
+                        field_type: #field_type,
+                        arguments: &[#(#arguments),*],
+                        ownership: juniper_from_schema::Ownership::#ownership,
+                        is_async: #is_async,
+                        infallible: #infallible,
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+fn gen_input_value_infos<'doc>(
+    naming: &Naming,
+    values: &'doc [InputValue<'doc, &'doc str>],
+) -> Vec<TokenStream> {
+    values
+        .iter()
+        .map(|value| {
+            let name = value.name;
+            let rust_name = naming.field_name(value.name, rust_name(&value.directives));
+            let description = quote_option_str(value.description.as_ref());
+            let value_type = value.value_type.to_string();
+            let default_value = value.default_value.as_ref().map(|value| value.to_string());
+            let default_value = quote_option_str(default_value.as_ref());
+
+            quote! {
+                juniper_from_schema::InputValueInfo {
+                    name: #name,
+                    rust_name: #rust_name,
+                    description: #description,
+                    value_type: #value_type,
+                    default_value: #default_value,
+                }
+            }
+        })
+        .collect()
+}
+
+fn quote_option_str(value: Option<&String>) -> TokenStream {
+    match value {
+        Some(value) => quote! { std::option::Option::Some(#value) },
+        None => quote! { std::option::Option::None },
+    }
+}
+
+impl<'pass, 'doc> SchemaVisitor<'doc> for SchemaInfoCodeGenPass<'pass, 'doc> {
+    fn visit_scalar_type(&mut self, scalar: &'doc ScalarType<'doc, &'doc str>) {
+        self.gen_type_info(
+            scalar.name,
+            quote! { Scalar },
+            scalar.description.as_ref(),
+            Vec::new(),
+            Vec::new(),
+        );
+    }
+
+    fn visit_object_type(&mut self, obj: &'doc ObjectType<'doc, &'doc str>) {
+        let fields = self.gen_field_infos(obj.name, &obj.fields);
+        self.gen_type_info(
+            obj.name,
+            quote! { Object },
+            obj.description.as_ref(),
+            fields,
+            Vec::new(),
+        );
+    }
+
+    fn visit_interface_type(&mut self, interface: &'doc InterfaceType<'doc, &'doc str>) {
+        let fields = self.gen_field_infos(interface.name, &interface.fields);
+        self.gen_type_info(
+            interface.name,
+            quote! { Interface },
+            interface.description.as_ref(),
+            fields,
+            Vec::new(),
+        );
+    }
+
+    fn visit_union_type(&mut self, union: &'doc UnionType<'doc, &'doc str>) {
+        self.gen_type_info(
+            union.name,
+            quote! { Union },
+            union.description.as_ref(),
+            Vec::new(),
+            Vec::new(),
+        );
+    }
+
+    fn visit_enum_type(&mut self, enum_type: &'doc EnumType<'doc, &'doc str>) {
+        self.gen_type_info(
+            enum_type.name,
+            quote! { Enum },
+            enum_type.description.as_ref(),
+            Vec::new(),
+            Vec::new(),
+        );
+    }
+
+    fn visit_input_object_type(&mut self, input_type: &'doc InputObjectType<'doc, &'doc str>) {
+        let input_fields = gen_input_value_infos(&self.pass.options.naming, &input_type.fields);
+        self.gen_type_info(
+            input_type.name,
+            quote! { InputObject },
+            input_type.description.as_ref(),
+            Vec::new(),
+            input_fields,
+        );
+    }
+}
@@ -1,26 +1,48 @@
+mod gen_directive_metadata;
 mod gen_query_trails;
+mod gen_schema_info;
 
 use super::{
-    directive_parsing::*, error::Error, schema_visitor::*, type_name, validations::*, AstData,
-    DateTimeScalarDefinition, EmitError, ErrorKind, NullableType, TypeKind,
+    derives::{derives_serde, Derives},
+    directive_parsing::*,
+    error::Error,
+    is_non_null, is_special_case_scalar,
+    naming::Naming,
+    rust_ident,
+    schema_visitor::*,
+    type_name,
+    validations::*,
+    AstData, DateTimeScalarDefinition, EmitError, ErrorKind, NullableType, TypeKind,
 };
 use graphql_parser::{schema, schema::Value, Pos};
-use heck::{CamelCase, SnakeCase};
-use proc_macro2::{Span, TokenStream};
+use heck::CamelCase;
+use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use std::{
-    collections::{BTreeMap, BTreeSet, HashSet},
+    collections::{BTreeMap, BTreeSet},
     convert::TryFrom,
+    path::Path,
 };
-use syn::{parse_quote, Ident, LitStr, Token};
+use syn::{ext::IdentExt, parse_quote, Ident, Token};
+
+#[derive(Debug)]
+pub struct CodeGenOptions {
+    pub context_type: syn::Type,
+    pub error_type: syn::Type,
+    pub schema_type_name: Ident,
+    pub query_trails_module_name: Ident,
+    pub visibility: syn::Visibility,
+    pub naming: Naming,
+    pub derives: Derives,
+}
 
 #[derive(Debug)]
 pub struct CodeGenPass<'doc> {
-    error_type: &'doc syn::Type,
-    context_type: &'doc syn::Type,
+    options: &'doc CodeGenOptions,
     errors: BTreeSet<Error>,
-    ast_data: AstData<'doc>,
+    pub(super) ast_data: AstData<'doc>,
     raw_schema: &'doc str,
+    schema_path: Option<&'doc Path>,
     scalars: Vec<Scalar<'doc>>,
     objects: Vec<Object<'doc>>,
     subscription: Option<Subscription<'doc>>,
@@ -29,21 +51,24 @@ pub struct CodeGenPass<'doc> {
     enums: Vec<Enum<'doc>>,
     input_objects: Vec<InputObject<'doc>>,
     schema_type: Option<SchemaType>,
+    /// Input fields whose default values are being quoted, for catching defaults that contain
+    /// themselves.
+    input_field_defaults_in_progress: Vec<Pos>,
 }
 
 impl<'doc> CodeGenPass<'doc> {
     pub fn new(
         raw_schema: &'doc str,
-        error_type: &'doc syn::Type,
-        context_type: &'doc syn::Type,
+        schema_path: Option<&'doc Path>,
+        options: &'doc CodeGenOptions,
         ast_data: AstData<'doc>,
     ) -> Self {
         Self {
-            error_type,
-            context_type,
+            options,
             ast_data,
             errors: BTreeSet::new(),
             raw_schema,
+            schema_path,
             scalars: Vec::new(),
             objects: Vec::new(),
             subscription: None,
@@ -52,6 +77,7 @@ impl<'doc> CodeGenPass<'doc> {
             enums: Vec::new(),
             input_objects: Vec::new(),
             schema_type: None,
+            input_field_defaults_in_progress: Vec::new(),
         }
     }
 
@@ -63,7 +89,10 @@ impl<'doc> CodeGenPass<'doc> {
         self.check_for_errors()?;
 
         let query_trail_tokens = self.gen_query_trails(doc);
+        let directive_metadata_tokens = self.gen_directive_metadata(doc);
+        let schema_info_tokens = self.gen_schema_info(doc);
         visit_document(&mut self, doc);
+        self.schema_type = self.gen_schema_type();
 
         self.check_for_errors()?;
 
@@ -77,11 +106,12 @@ impl<'doc> CodeGenPass<'doc> {
             input_objects,
             schema_type,
 
-            error_type: _,
-            context_type: _,
+            options: _,
             errors: _,
             ast_data: _,
             raw_schema: _,
+            schema_path: _,
+            input_field_defaults_in_progress: _,
         } = self;
 
         let mut tokens = quote! {
@@ -100,89 +130,140 @@ impl<'doc> CodeGenPass<'doc> {
         // eprintln!("\n");
 
         tokens.extend(query_trail_tokens);
+        tokens.extend(directive_metadata_tokens);
+        tokens.extend(schema_info_tokens);
 
         Ok(tokens)
     }
 
     fn validate_doc(&mut self, doc: &'doc schema::Document<'doc, &'doc str>) {
-        let mut validation_visitor =
-            FieldNameCaseValidator::new().and(UuidNameCaseValidator::new());
+        let mut validation_visitor = FieldNameCaseValidator::new()
+            .and(UuidNameCaseValidator::new())
+            .and(RustNameValidator::new(
+                self.options.schema_type_name.to_string(),
+                self.options.naming.clone(),
+            ));
 
         visit_document(&mut validation_visitor, doc);
 
-        let (field_validator, uuid_name_validator) = validation_visitor.into_inner();
+        let (validators, rust_name_validator) = validation_visitor.into_inner();
+        let (field_validator, uuid_name_validator) = validators.into_inner();
         for error in field_validator
             .errors
             .into_iter()
             .chain(uuid_name_validator.errors)
+            .chain(rust_name_validator.errors)
         {
             self.errors.insert(error);
         }
     }
 
-    fn check_for_errors(&self) -> Result<(), BTreeSet<Error>> {
-        if self.errors.is_empty() {
-            Ok(())
+    /// The Rust name of a type declared in the schema.
+    fn rust_type_name(&self, name: &str) -> Ident {
+        let rust_name = self.ast_data.type_rust_name(name);
+        rust_ident(&self.options.naming.type_name(name, rust_name))
+    }
+
+    /// The Rust type of an object, interface, or union for use in `QueryTrail<'r, T, K>`.
+    fn query_trail_type_name(&self, name: &str) -> TypeName {
+        self.rust_type_ref(name).for_query_trail()
+    }
+
+    /// The Rust type of an object, interface, or union, without its lifetime. Objects can be
+    /// bound to a type defined elsewhere with `@juniper(rust_path: "...")`.
+    fn rust_type(&self, name: &str) -> TypeName {
+        TypeName {
+            ident: self.rust_type_name(name),
+            path: self.ast_data.type_rust_path(name).cloned(),
+            lifetime: None,
+        }
+    }
+
+    /// The Rust type of an object, interface, or union for use in signatures, with an elided
+    /// lifetime if it has one.
+    fn rust_type_ref(&self, name: &str) -> TypeName {
+        let ty = self.rust_type(name);
+        if self.ast_data.has_lifetime(name) {
+            ty.with_lifetime(parse_quote! { '_ })
         } else {
-            Err(self.errors.clone())
+            ty
         }
     }
-}
 
-impl<'doc> EmitError for CodeGenPass<'doc> {
-    fn emit_error(&mut self, pos: Pos, kind: ErrorKind) {
-        self.errors.emit_error(pos, kind)
+    /// The Rust type of an object for use where it is defined or implemented, with a named
+    /// lifetime if it has one.
+    fn rust_type_definition(&self, name: &str) -> TypeName {
+        let ty = self.rust_type(name);
+        if self.ast_data.has_lifetime(name) {
+            ty.with_lifetime(definition_lifetime())
+        } else {
+            ty
+        }
     }
-}
 
-impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
-    fn visit_schema_definition(&mut self, node: &'doc schema::SchemaDefinition<'doc, &'doc str>) {
-        let schema::SchemaDefinition {
-            position,
-            directives: _,
-            query,
-            mutation,
-            subscription,
-        } = node;
+    fn input_field_rust_name(&self, type_name: &str, field_name: &str) -> Ident {
+        let rust_name = self.ast_data.member_rust_name(type_name, field_name);
+        rust_ident(&self.options.naming.field_name(field_name, rust_name))
+    }
 
-        let query_type = match query {
-            Some(query) => {
-                let ident = format_ident!("{}", query);
-                parse_quote! { #ident }
-            }
-            None => {
-                self.emit_error(*position, ErrorKind::NoQueryType);
-                return;
-            }
-        };
+    fn gen_schema_type(&self) -> Option<SchemaType> {
+        let root_types = self.ast_data.root_types();
+        let context_type = &self.options.context_type;
+
+        let query_type = root_types.query.map(|query| {
+            let ty = self.rust_type(query);
+            parse_quote! { #ty }
+        })?;
 
-        let mutation_type = match mutation {
+        let mutation_type = match root_types.mutation {
             Some(mutation) => {
-                let ident = format_ident!("{}", mutation);
-                parse_quote! { #ident }
-            }
-            None => {
-                let context_type = &self.context_type;
-                parse_quote! { juniper_from_schema::juniper::EmptyMutation<#context_type> }
+                let ty = self.rust_type(mutation);
+                parse_quote! { #ty }
             }
+            None => parse_quote! { juniper_from_schema::juniper::EmptyMutation<#context_type> },
         };
 
-        let subscription_type = match subscription {
+        let subscription_type = match root_types.subscription {
             Some(subscription) => {
-                let ident = format_ident!("{}", subscription);
-                parse_quote! { #ident }
+                let ty = self.rust_type(subscription);
+                parse_quote! { #ty }
             }
             None => {
-                let context_type = &self.context_type;
                 parse_quote! { juniper_from_schema::juniper::EmptySubscription<#context_type> }
             }
         };
 
-        self.schema_type = Some(SchemaType {
+        Some(SchemaType {
+            name: self.options.schema_type_name.clone(),
+            visibility: self.options.visibility.clone(),
             query_type,
             mutation_type,
             subscription_type,
-        });
+        })
+    }
+
+    fn check_for_errors(&self) -> Result<(), BTreeSet<Error>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors.clone())
+        }
+    }
+}
+
+impl<'doc> EmitError for CodeGenPass<'doc> {
+    fn emit_error(&mut self, pos: Pos, kind: ErrorKind) {
+        self.errors.emit_error(pos, kind)
+    }
+}
+
+impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
+    fn visit_schema_definition(&mut self, node: &'doc schema::SchemaDefinition<'doc, &'doc str>) {
+        let () = self.parse_directives(node);
+
+        if node.query.is_none() {
+            self.emit_error(node.position, ErrorKind::NoQueryType);
+        }
     }
 
     fn visit_directive_definition(
@@ -196,19 +277,11 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
 
     fn visit_scalar_type(&mut self, node: &'doc schema::ScalarType<'doc, &'doc str>) {
         match &*node.name {
-            name if name == crate::DATE_TIME_SCALAR_NAME => {
-                // This case is special because it supports a directive. We don't need to parse and
-                // check the it though that is done by `AstData::visit_scalar_type`
-
-                if node.description.is_some() {
-                    self.emit_error(node.position, ErrorKind::SpecialCaseScalarWithDescription);
-                }
-            }
-            name if name == crate::DATE_SCALAR_NAME
-                || name == crate::URL_SCALAR_NAME
-                || name == crate::UUID_SCALAR_NAME =>
-            {
-                let () = self.parse_directives(node);
+            name if is_special_case_scalar(name) => {
+                // The Rust types for these scalars come from other crates so there is nowhere to
+                // put the `@specifiedBy` URL. `@juniper` on `DateTimeUtc` is checked by
+                // `AstData::visit_scalar_type`.
+                let _ = self.parse_directives(node);
 
                 if node.description.is_some() {
                     self.emit_error(node.position, ErrorKind::SpecialCaseScalarWithDescription);
@@ -222,7 +295,8 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                     directives: _,
                 } = node;
 
-                let () = self.parse_directives(node);
+                let (specified_by, derives) = self.parse_directives(node);
+                let derives = derives.unwrap_or_else(|| self.options.derives.scalars.clone());
 
                 match &**name {
                     "String" | "Float" | "Int" | "Boolean" | "ID" => {
@@ -232,8 +306,13 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                 }
 
                 self.scalars.push(Scalar {
-                    name: format_ident!("{}", name),
+                    name: self.rust_type_name(name),
+                    graphql_name: name,
                     description: description.as_ref(),
+                    specified_by,
+                    derives,
+                    query_trails_module_name: &self.options.query_trails_module_name,
+                    visibility: &self.options.visibility,
                 });
             }
         };
@@ -251,37 +330,64 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
 
         let () = self.parse_directives(node);
 
-        if self.ast_data.is_subscription_type(name) {
+        let graphql_name = *name;
+        let fields_trait_name = self
+            .options
+            .naming
+            .fields_trait_name(&self.rust_type_name(name));
+        let name = self.rust_type_definition(name);
+
+        let has_lifetime = self.ast_data.has_lifetime(graphql_name);
+        if has_lifetime && self.ast_data.is_root_type(graphql_name) {
+            self.emit_error(*position, ErrorKind::LifetimeOnRootType);
+        }
+
+        if self.ast_data.is_subscription_type(graphql_name) {
             if !implements_interfaces.is_empty() {
                 self.emit_error(*position, ErrorKind::SubscriptionsCannotImplementInterfaces);
             }
 
             let fields = fields
                 .iter()
-                .map(|field| self.graphql_field_to_rust_field(field, FieldLocation::Subscription))
+                .map(|field| {
+                    self.graphql_field_to_rust_field(
+                        graphql_name,
+                        field,
+                        FieldLocation::Subscription,
+                    )
+                })
                 .collect();
 
             self.subscription = Some(Subscription {
-                name: format_ident!("{}", name),
+                name,
+                graphql_name,
+                fields_trait_name,
                 description: description.as_ref(),
-                context_type: self.context_type,
+                context_type: &self.options.context_type,
+                visibility: &self.options.visibility,
                 fields,
             });
         } else {
             let fields = fields
                 .iter()
-                .map(|field| self.graphql_field_to_rust_field(field, FieldLocation::Object))
+                .map(|field| {
+                    self.graphql_field_to_rust_field(graphql_name, field, FieldLocation::Object)
+                })
                 .collect();
 
             let implements_interfaces = implements_interfaces
                 .iter()
-                .map(|name| format_ident!("{}", name))
+                .map(|name| self.rust_type_ref(name))
                 .collect();
 
             self.objects.push(Object {
-                name: format_ident!("{}", name),
+                name,
+                has_lifetime,
+                graphql_name,
+                fields_trait_name,
                 description: description.as_ref(),
-                context_type: self.context_type,
+                context_type: &self.options.context_type,
+                visibility: &self.options.visibility,
                 fields,
                 implements_interfaces,
             });
@@ -299,28 +405,46 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
 
         let () = self.parse_directives(node);
 
-        let implementors = self
+        let implementor_graphql_names = self
             .ast_data
             .get_implementors_of_interface(name)
             .cloned()
-            .unwrap_or_else(Vec::new)
-            .into_iter()
-            .map(|name| format_ident!("{}", name))
+            .unwrap_or_else(Vec::new);
+        let implementors = implementor_graphql_names
+            .iter()
+            .map(|name| self.rust_type_definition(name))
             .collect::<Vec<_>>();
+        let implementor_fields_trait_names = implementors
+            .iter()
+            .map(|implementor| self.options.naming.fields_trait_name(&implementor.ident))
+            .collect();
 
-        let name = format_ident!("{}", name);
+        let graphql_name = *name;
+        let name = self.rust_type_name(name);
         let fields = fields
             .iter()
-            .map(|field| self.graphql_field_to_rust_field(field, FieldLocation::Interface))
-            .collect();
+            .map(|field| {
+                self.graphql_field_to_rust_field(graphql_name, field, FieldLocation::Interface)
+            })
+            .collect::<Vec<_>>();
+
+        for implementor in implementor_graphql_names {
+            for field in &fields {
+                self.validate_implementor_field(graphql_name, field, implementor);
+            }
+        }
 
         self.interfaces.push(Interface {
             description: description.as_ref(),
             trait_name: format_ident!("{}Interface", name),
+            has_lifetime: self.ast_data.has_lifetime(graphql_name),
             name,
+            graphql_name,
             fields,
             implementors,
-            context_type: self.context_type,
+            implementor_fields_trait_names,
+            context_type: &self.options.context_type,
+            visibility: &self.options.visibility,
         });
     }
 
@@ -335,7 +459,8 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
 
         let () = self.parse_directives(node);
 
-        let name = format_ident!("{}", name);
+        let graphql_name = *name;
+        let name = self.rust_type_name(name);
 
         let variants = types
             .iter()
@@ -345,20 +470,28 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                 let type_inside = self
                     .graphql_type_to_rust_type(&graphql_type, false, *position)
                     .remove_one_layer_of_nullability_by_value();
-                let ident = format_ident!("{}", variant_name);
+                // The variant is used in the enum definition where the lifetime can't be elided
+                let type_inside = Box::new(type_inside.map_innermost(|ty| match ty {
+                    Type::Object(name) if name.lifetime.is_some() => {
+                        Type::Object(name.with_lifetime(definition_lifetime()))
+                    }
+                    other => other,
+                }));
                 UnionVariant {
-                    graphql_name: ident.clone(),
-                    rust_name: ident,
+                    rust_name: self.rust_type_name(variant_name),
                     type_inside,
                 }
             })
             .collect::<Vec<_>>();
 
         self.unions.push(Union {
+            has_lifetime: self.ast_data.has_lifetime(graphql_name),
             name,
+            graphql_name,
             variants,
             description: description.as_ref(),
-            context_type: self.context_type,
+            context_type: &self.options.context_type,
+            visibility: &self.options.visibility,
         })
     }
 
@@ -371,9 +504,18 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
             directives: _,
         } = node;
 
-        let () = self.parse_directives(node);
+        let derives = self.parse_directives(node);
+
+        let graphql_name = *name;
+        let name = self.rust_type_name(name);
+        let rust_type = self.ast_data.type_rust_path(graphql_name).cloned();
 
-        let name = format_ident!("{}", name);
+        // The hidden glue type for `rust_type` doesn't get any derives
+        let derives = match rust_type {
+            Some(_) => Vec::new(),
+            None => derives.unwrap_or_else(|| self.options.derives.enums.clone()),
+        };
+        let serde = derives_serde(&derives);
 
         let variants = values
             .iter()
@@ -382,11 +524,16 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                     name,
                     description,
                     position: _,
-                    directives: _,
+                    directives,
                 } = value;
 
                 let graphql_name = *name;
-                let name = format_ident!("{}", name.to_camel_case());
+                let name = format_ident!(
+                    "{}",
+                    self.options
+                        .naming
+                        .enum_value_name(name, rust_name(directives))
+                );
                 let deprecation = self.parse_directives(value);
 
                 EnumVariant {
@@ -394,14 +541,20 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                     deprecation,
                     description: description.as_ref(),
                     graphql_name,
+                    serde,
                 }
             })
             .collect();
 
         self.enums.push(Enum {
+            rust_type,
             name,
+            graphql_name,
             variants,
+            derives,
             description: description.as_ref(),
+            query_trails_module_name: &self.options.query_trails_module_name,
+            visibility: &self.options.visibility,
         })
     }
 
@@ -415,9 +568,18 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
             directives: _,
         } = node;
 
-        let () = self.parse_directives(node);
+        let derives = self.parse_directives(node);
+
+        let graphql_name = *name;
+        let name = self.rust_type_name(name);
+        let rust_type = self.ast_data.type_rust_path(graphql_name).cloned();
+
+        let derives = match rust_type {
+            Some(_) => Vec::new(),
+            None => derives.unwrap_or_else(|| self.options.derives.input_objects.clone()),
+        };
+        let serde = derives_serde(&derives);
 
-        let name = format_ident!("{}", name);
         let fields = fields
             .iter()
             .map(|field| {
@@ -427,31 +589,44 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                     value_type,
                     default_value,
                     position,
-                    directives: _,
+                    directives,
                 } = field;
 
-                let () = self.parse_directives(field);
+                let deprecation = self.parse_directives(field);
 
-                if default_value.is_some() {
-                    self.emit_error(*position, ErrorKind::InputTypeFieldWithDefaultValue);
+                if deprecation.is_deprecated() && default_value.is_none() && is_non_null(value_type)
+                {
+                    self.emit_error(*position, ErrorKind::DeprecatedRequiredInputValue);
                 }
 
-                let ty = self.graphql_type_to_rust_type(value_type, false, *position);
+                let default_value_graphql = default_value.as_ref().map(|v| v.to_string());
+                let (ty, default_value) = self.input_field_type_and_default(field);
 
-                let name = format_ident!("{}", name.to_snake_case());
+                let graphql_name = *name;
+                let name = rust_ident(&self.options.naming.field_name(name, rust_name(directives)));
 
                 InputObjectField {
                     name,
+                    graphql_name,
                     ty,
+                    default_value,
+                    default_value_graphql,
                     description: description.as_ref(),
+                    deprecation,
+                    serde,
                 }
             })
             .collect::<Vec<_>>();
 
         self.input_objects.push(InputObject {
+            rust_type,
             name,
+            graphql_name,
             description: description.as_ref(),
             fields,
+            derives,
+            query_trails_module_name: &self.options.query_trails_module_name,
+            visibility: &self.options.visibility,
         });
     }
 
@@ -501,6 +676,7 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
 impl<'doc> CodeGenPass<'doc> {
     fn graphql_field_to_rust_field(
         &mut self,
+        parent_type_name: &'doc str,
         field: &'doc schema::Field<'doc, &'doc str>,
         field_location: FieldLocation,
     ) -> Field<'doc> {
@@ -513,7 +689,7 @@ impl<'doc> CodeGenPass<'doc> {
             directives: _,
         } = field;
 
-        let field_directives = self.parse_directives(field);
+        let field_directives = self.parse_directives(FieldOfType(parent_type_name, field));
 
         self.validate_directive_for_field(&field_directives, field_location, *position);
 
@@ -526,53 +702,198 @@ impl<'doc> CodeGenPass<'doc> {
                     name,
                     value_type,
                     default_value,
-                    directives: _,
+                    directives,
                 } = arg;
 
-                let () = self.parse_directives(arg);
+                let deprecation = self.parse_directives(arg);
+
+                if deprecation.is_deprecated() && default_value.is_none() && is_non_null(value_type)
+                {
+                    self.emit_error(*position, ErrorKind::DeprecatedRequiredInputValue);
+                }
 
+                let default_value_graphql = default_value.as_ref().map(|v| v.to_string());
                 let default_value = default_value
                     .as_ref()
                     .map(|v| self.quote_value(v, type_name(value_type), *position));
 
-                let ty = self.graphql_type_to_rust_type(value_type, false, *position);
+                let ty = self.input_value_type(arg);
 
                 if default_value.is_some() && !ty.is_nullable() {
                     self.emit_error(*position, ErrorKind::NonnullableFieldWithDefaultValue);
                 }
 
-                let name_without_raw_ident = format_ident!("{}", name.to_snake_case());
+                let rust_name = rust_name(directives);
+                let name_without_raw_ident =
+                    format_ident!("{}", self.options.naming.field_name(name, rust_name));
                 FieldArg {
                     name: format_ident!("r#{}", name_without_raw_ident),
                     name_without_raw_ident,
+                    // The GraphQL name only has to be given if juniper can't derive it
+                    renamed_from: rust_name.map(|_| *name),
                     description: description.as_ref(),
                     ty,
                     default_value,
+                    default_value_graphql,
+                    deprecation,
                 }
             })
             .collect();
 
-        let return_type = self.graphql_type_to_rust_type(
+        let mut return_type = self.graphql_type_to_rust_type(
             field_type,
             field_directives.ownership.is_as_ref(),
             *position,
         );
 
-        if field_directives.ownership == Ownership::AsRef && !return_type.supports_as_ref() {
-            self.emit_error(*position, ErrorKind::AsRefOwnershipForNamedType);
+        match field_directives.ownership {
+            Ownership::Owned | Ownership::Borrowed => {}
+            Ownership::AsRef => {
+                if !return_type.supports_as_ref() {
+                    self.emit_error(*position, ErrorKind::AsRefOwnershipForNamedType);
+                }
+            }
+            Ownership::Arc => {
+                return_type = return_type.map_innermost(|ty| Type::Arc(Box::new(ty)));
+            }
+            Ownership::Str | Ownership::Cow => {
+                if type_name(field_type) == "String" {
+                    let borrowed = if field_directives.ownership == Ownership::Str {
+                        Type::Str
+                    } else {
+                        Type::CowStr
+                    };
+                    return_type = return_type.map_innermost(|_| borrowed);
+                } else {
+                    self.emit_error(
+                        *position,
+                        ErrorKind::UnsupportedOwnershipForType {
+                            ownership: field_directives.ownership.name(),
+                            supported_types: "`String` fields",
+                        },
+                    );
+                }
+            }
+            Ownership::Slice | Ownership::Iter => {
+                let ownership = field_directives.ownership;
+                let list = return_type.clone().map_outermost_list(|item| {
+                    if ownership == Ownership::Slice {
+                        Type::Slice(Box::new(item))
+                    } else {
+                        Type::Iter(Box::new(item))
+                    }
+                });
+
+                match list {
+                    Some(list) => return_type = list,
+                    None => self.emit_error(
+                        *position,
+                        ErrorKind::UnsupportedOwnershipForType {
+                            ownership: ownership.name(),
+                            supported_types: "list fields",
+                        },
+                    ),
+                }
+
+                if ownership == Ownership::Iter && field_directives.r#async.value {
+                    self.emit_error(*position, ErrorKind::IterOwnershipOnAsyncField);
+                }
+            }
         }
 
+        let rust_name = field_directives
+            .rust_name
+            .as_ref()
+            .map(|rust_name| rust_name.value.as_str());
+
+        let name_without_raw_ident =
+            format_ident!("{}", self.options.naming.field_name(name, rust_name));
+
+        let args_struct = if field_directives.args_struct.value && !arguments.is_empty() {
+            Some(args_struct_name(
+                &self.rust_type_name(parent_type_name),
+                &name_without_raw_ident,
+            ))
+        } else {
+            None
+        };
+
+        let error_type = self.field_error_type(&field_directives);
+
         Field {
             description: description.as_ref(),
-            name: format_ident!("r#{}", name.to_snake_case()),
-            context_type: self.context_type,
-            error_type: self.error_type,
+            name: format_ident!("r#{}", name_without_raw_ident),
+            graphql_name: name,
+            trait_field_name: self
+                .options
+                .naming
+                .field_method_name(&name_without_raw_ident),
+            context_type: &self.options.context_type,
+            error_type,
             args,
+            args_struct,
             return_type,
             directives: field_directives,
         }
     }
 
+    /// The type of an argument or input field. Nullable ones are `Nullable<T>` rather than
+    /// `Option<T>` with `@juniper(nullable_kind: "tristate")`.
+    fn input_value_type(&mut self, input_value: &'doc schema::InputValue<'doc, &'doc str>) -> Type {
+        let ty =
+            self.graphql_type_to_rust_type(&input_value.value_type, false, input_value.position);
+
+        match nullable_kind(&input_value.directives) {
+            NullableKind::Option => ty,
+            NullableKind::Tristate => {
+                if ty.is_nullable() && input_value.default_value.is_none() {
+                    Type::Tristate(ty.remove_one_layer_of_nullability_by_value())
+                } else {
+                    self.emit_error(input_value.position, ErrorKind::TristateNotNullable);
+                    ty
+                }
+            }
+        }
+    }
+
+    /// The type of an input field along with its default value, if it has one. The default value is
+    /// only used when the field is left out, so the field remains nullable.
+    fn input_field_type_and_default(
+        &mut self,
+        field: &'doc schema::InputValue<'doc, &'doc str>,
+    ) -> (Type, Option<TokenStream>) {
+        let ty = self.input_value_type(field);
+
+        // A default of `null` is the same as no default
+        let default_value = match &field.default_value {
+            Some(Value::Null) | None => return (ty, None),
+            Some(default_value) => default_value,
+        };
+
+        if !ty.is_nullable() {
+            self.emit_error(field.position, ErrorKind::NonnullableFieldWithDefaultValue);
+            return (ty, None);
+        }
+
+        if self
+            .input_field_defaults_in_progress
+            .contains(&field.position)
+        {
+            self.emit_error(field.position, ErrorKind::RecursiveInputFieldDefaultValue);
+            return (ty, None);
+        }
+
+        self.input_field_defaults_in_progress.push(field.position);
+        let default_value =
+            self.quote_value(default_value, type_name(&field.value_type), field.position);
+        self.input_field_defaults_in_progress.pop();
+
+        (
+            ty,
+            Some(quote! { std::option::Option::Some(#default_value) }),
+        )
+    }
+
     fn graphql_type_to_rust_type(
         &mut self,
         graphql_type: &schema::Type<'doc, &'doc str>,
@@ -588,15 +909,15 @@ impl<'doc> CodeGenPass<'doc> {
                 "ID" => Type::Scalar(Either::A(parse_quote! { juniper_from_schema::juniper::ID })),
                 name => {
                     if pass.ast_data.is_scalar(name) {
-                        Type::Scalar(Either::B(format_ident!("{}", name)))
+                        Type::Scalar(Either::B(pass.rust_type_name(name)))
                     } else if pass.ast_data.is_enum_type(name) {
-                        Type::Enum(format_ident!("{}", name))
+                        Type::Enum(pass.rust_type(name))
                     } else if pass.ast_data.is_union_type(name) {
-                        Type::Union(format_ident!("{}", name))
+                        Type::Union(pass.rust_type_ref(name))
                     } else if pass.ast_data.is_interface_type(name) {
-                        Type::Interface(format_ident!("{}", name))
+                        Type::Interface(pass.rust_type_ref(name))
                     } else {
-                        Type::Object(format_ident!("{}", name))
+                        Type::Object(pass.rust_type_ref(name))
                     }
                 }
             }
@@ -698,13 +1019,17 @@ impl<'doc> CodeGenPass<'doc> {
             Value::Boolean(inner) => quote! { #inner },
 
             Value::Enum(variant_name) => {
-                let type_name = format_ident!("{}", type_name.to_camel_case());
-                let variant_name = format_ident!("{}", variant_name.to_camel_case());
+                let rust_name = self.ast_data.member_rust_name(type_name, variant_name);
+                let variant_name = format_ident!(
+                    "{}",
+                    self.options.naming.enum_value_name(variant_name, rust_name)
+                );
+                let type_name = self.rust_type(type_name);
                 quote! { #type_name::#variant_name }
             }
 
             Value::List(list) => {
-                let mut acc = quote! { let mut vec = Vec::new(); };
+                let mut acc = quote! { let mut vec = std::vec::Vec::new(); };
                 for value in list {
                     let value_quoted = self.quote_value(value, type_name, pos);
                     acc.extend(quote! { vec.push(#value_quoted); });
@@ -720,7 +1045,7 @@ impl<'doc> CodeGenPass<'doc> {
                 quote! {}
             }
 
-            Value::Null => quote! { None },
+            Value::Null => quote! { std::option::Option::None },
         }
     }
 
@@ -730,51 +1055,42 @@ impl<'doc> CodeGenPass<'doc> {
         type_name: &'doc str,
         pos: Pos,
     ) -> TokenStream {
-        let name = format_ident!("{}", type_name);
+        let name = self.rust_type(type_name);
 
-        let mut fields_seen: HashSet<&'doc str> = HashSet::new();
-
-        // Set fields given in `map`
-        let mut field_assigments = map
-            .iter()
-            .map(|(key, value)| {
-                fields_seen.insert(key);
-                let field_name = format_ident!("{}", key.to_snake_case());
-
-                let field_type_name = self
-                    .ast_data
-                    .input_object_field_type_name(&type_name, &key)
-                    .unwrap_or_else(|| {
-                        panic!("input_object_field_type_name {} {}", type_name, key)
-                    });
-
-                let value_quote = self.quote_value(value, field_type_name, pos);
-                match self
-                    .ast_data
-                    .input_object_field_is_nullable(&type_name, &key)
-                {
-                    Some(true) | None => {
-                        if value == &Value::Null {
-                            quote! { #field_name: #value_quote }
-                        } else {
-                            quote! { #field_name: Some(#value_quote) }
+        let fields = self
+            .ast_data
+            .input_object_fields(type_name)
+            .unwrap_or_else(|| panic!("input_object_fields {}", type_name));
+
+        let mut field_assigments = Vec::new();
+        for field in fields {
+            let field_name = self.input_field_rust_name(type_name, field.name);
+            let (ty, default_value) = self.input_field_type_and_default(field);
+
+            let value_quote = match (map.get(field.name), default_value) {
+                (None, Some(default_value)) => default_value,
+                (Some(Value::Null), _) => match ty {
+                    Type::Tristate(_) => {
+                        quote! { juniper_from_schema::juniper::Nullable::ExplicitNull }
+                    }
+                    _ => quote! { std::option::Option::None },
+                },
+                (None, None) => input_field_missing_value(&ty)
+                    .unwrap_or_else(|| quote! { std::option::Option::None }),
+                (Some(value), _) => {
+                    let value_quote =
+                        self.quote_value(value, super::type_name(&field.value_type), pos);
+                    match ty {
+                        Type::Nullable(_) => quote! { std::option::Option::Some(#value_quote) },
+                        Type::Tristate(_) => {
+                            quote! { juniper_from_schema::juniper::Nullable::Some(#value_quote) }
                         }
+                        _ => value_quote,
                     }
-                    Some(false) => quote! { #field_name: #value_quote },
                 }
-            })
-            .collect::<Vec<_>>();
+            };
 
-        // Set fields not given in map to `None`
-        if let Some(fields) = self.ast_data.input_object_field_names(&type_name) {
-            for field_name in fields {
-                if !fields_seen.contains(field_name) {
-                    let field_name = format_ident!("{}", field_name.to_snake_case());
-                    field_assigments.push(quote! {
-                        #field_name: None
-                    });
-                }
-            }
+            field_assigments.push(quote! { #field_name: #value_quote });
         }
 
         let tokens = quote! {
@@ -805,6 +1121,17 @@ impl<'doc> CodeGenPass<'doc> {
                 DirectiveLocation::Scalar => {
                     scalar_location_present = true;
                 }
+                // Only needed for settings that apply to all fields
+                DirectiveLocation::Schema => {}
+                // Only needed for `rust_name`
+                DirectiveLocation::Object
+                | DirectiveLocation::Interface
+                | DirectiveLocation::Union
+                | DirectiveLocation::Enum
+                | DirectiveLocation::EnumValue
+                | DirectiveLocation::InputObject
+                | DirectiveLocation::InputFieldDefinition
+                | DirectiveLocation::ArgumentDefinition => {}
                 other => self.emit_error(
                     directive.position,
                     ErrorKind::InvalidJuniperDirective(
@@ -944,13 +1271,30 @@ impl<'doc> CodeGenPass<'doc> {
                     no_directives(self, arg, name);
                     default_value(self, arg, Value::Null, name);
                 }
+                // Optional so definitions written before they were added remain valid
+                name @ "rust_name" | name @ "error_type" | name @ "rust_path"
+                | name @ "rust_type" | name @ "derive" | name @ "nullable_kind" => {
+                    of_type(self, arg, GraphqlType::NamedType("String"), name);
+                    no_directives(self, arg, name);
+                    default_value(self, arg, Value::Null, name);
+                }
+                name @ "args_struct" | name @ "lifetime" => {
+                    of_type(self, arg, GraphqlType::NamedType("Boolean"), name);
+                    no_directives(self, arg, name);
+                    default_value(self, arg, Value::Boolean(false), name);
+                }
+                name @ "executor" => {
+                    of_type(self, arg, GraphqlType::NamedType("Boolean"), name);
+                    no_directives(self, arg, name);
+                    default_value(self, arg, Value::Boolean(true), name);
+                }
                 name => {
                     self.emit_error(
                         arg.position,
                         ErrorKind::InvalidJuniperDirective(
                             format!("Invalid argument for @juniper directive: `{}`", name),
                             Some(
-                                "Supported arguments are `ownership`, `infallible`, `with_time_zone`, `async`, `stream_item_infallible`, and `stream_type`".to_string()
+                                "Supported arguments are `ownership`, `infallible`, `with_time_zone`, `async`, `stream_item_infallible`, `stream_type`, `rust_name`, `error_type`, `args_struct`, `executor`, `lifetime`, `rust_path`, `rust_type`, `derive`, and `nullable_kind`".to_string()
                             ),
                         ),
                     )
@@ -1016,6 +1360,68 @@ impl<'doc> CodeGenPass<'doc> {
         }
     }
 
+    fn field_error_type(&self, directives: &FieldDirectives) -> syn::Type {
+        // Invalid types have already been reported while parsing the directive
+        directives
+            .error_type
+            .as_ref()
+            .and_then(|error_type| syn::parse_str(&error_type.value).ok())
+            .unwrap_or_else(|| self.options.error_type.clone())
+    }
+
+    /// The interface's generated code calls the implementor's trait method for the field, so the
+    /// settings that change the method's signature or return type must be the same on both.
+    fn validate_implementor_field(
+        &mut self,
+        interface_name: &str,
+        interface_field: &Field<'doc>,
+        implementor: &'doc str,
+    ) {
+        let field = match self
+            .ast_data
+            .object_field(implementor, interface_field.graphql_name)
+        {
+            Some(field) => field,
+            None => return,
+        };
+        let directives = self.parse_directives(FieldOfType(implementor, field));
+
+        let mut mismatches = Vec::new();
+        if directives.ownership != interface_field.directives.ownership {
+            mismatches.push("ownership");
+        }
+        if directives.infallible.value != interface_field.directives.infallible.value {
+            mismatches.push("infallible");
+        }
+        if directives.r#async.value != interface_field.directives.r#async.value {
+            mismatches.push("async");
+        }
+        if !interface_field.args.is_empty()
+            && directives.args_struct.value != interface_field.directives.args_struct.value
+        {
+            mismatches.push("args_struct");
+        }
+        if directives.executor.value != interface_field.directives.executor.value {
+            mismatches.push("executor");
+        }
+        if !interface_field.directives.infallible.value
+            && self.field_error_type(&directives) != interface_field.error_type
+        {
+            mismatches.push("error_type");
+        }
+
+        for setting in mismatches {
+            self.emit_error(
+                field.position,
+                ErrorKind::InterfaceFieldSettingMismatch {
+                    setting,
+                    interface_field: format!("{}.{}", interface_name, field.name),
+                    implementor_field: format!("{}.{}", implementor, field.name),
+                },
+            );
+        }
+    }
+
     fn validate_directive_for_field(
         &mut self,
         directives: &FieldDirectives,
@@ -1034,10 +1440,15 @@ impl<'doc> CodeGenPass<'doc> {
             }
             FieldLocation::Subscription => {
                 match &directives.ownership {
-                    Ownership::Borrowed | Ownership::AsRef => {
+                    Ownership::Borrowed
+                    | Ownership::AsRef
+                    | Ownership::Str
+                    | Ownership::Cow
+                    | Ownership::Slice
+                    | Ownership::Iter => {
                         self.emit_error(pos, ErrorKind::SubscriptionFieldMustBeOwned);
                     }
-                    Ownership::Owned => {}
+                    Ownership::Owned | Ownership::Arc => {}
                 }
 
                 if let Some(ty) = &directives.stream_type {
@@ -1072,13 +1483,24 @@ where
 #[derive(Debug, Clone)]
 enum Type {
     Scalar(Either<syn::Type, Ident>),
-    Enum(Ident),
-    Union(Ident),
-    Interface(Ident),
-    Object(Ident),
+    Enum(TypeName),
+    Union(TypeName),
+    Interface(TypeName),
+    Object(TypeName),
     Ref(Box<Type>),
+    Arc(Box<Type>),
+    /// `&str`, for `@juniper(ownership: "str")`.
+    Str,
+    /// `Cow<str>`, for `@juniper(ownership: "cow")`.
+    CowStr,
+    /// `&[T]`, for `@juniper(ownership: "slice")`.
+    Slice(Box<Type>),
+    /// `impl IntoIterator<Item = T>`, for `@juniper(ownership: "iter")`.
+    Iter(Box<Type>),
     List(Box<Type>),
     Nullable(Box<Type>),
+    /// juniper's `Nullable<T>`, for `@juniper(nullable_kind: "tristate")`.
+    Tristate(Box<Type>),
 }
 
 impl Type {
@@ -1094,8 +1516,14 @@ impl Type {
             Type::Interface(_) => false,
             Type::Object(_) => false,
             Type::Ref(_) => false,
+            Type::Arc(_) => false,
+            Type::Str => false,
+            Type::CowStr => false,
+            Type::Slice(_) => false,
+            Type::Iter(_) => false,
             Type::List(_) => true,
             Type::Nullable(_) => true,
+            Type::Tristate(_) => false,
         }
     }
 
@@ -1121,8 +1549,74 @@ impl Type {
             Type::Object(_) => TypeKind::Type,
             Type::Interface { .. } => TypeKind::Type,
             Type::Ref(inner) => inner.kind(),
+            Type::Arc(inner) => inner.kind(),
+            Type::Str | Type::CowStr => TypeKind::Scalar,
+            Type::Slice(inner) | Type::Iter(inner) => inner.kind(),
             Type::List(inner) => inner.kind(),
-            Type::Nullable(inner) => inner.kind(),
+            Type::Nullable(inner) | Type::Tristate(inner) => inner.kind(),
+        }
+    }
+
+    /// Change the type of the values inside lists and options.
+    fn map_innermost(self, f: impl FnOnce(Type) -> Type) -> Type {
+        match self {
+            Type::List(inner) => Type::List(Box::new(inner.map_innermost(f))),
+            Type::Nullable(inner) => Type::Nullable(Box::new(inner.map_innermost(f))),
+            other => f(other),
+        }
+    }
+
+    /// Replace the outermost list with another type made from its item type, or `None` if this
+    /// isn't a list.
+    fn map_outermost_list(self, f: impl FnOnce(Type) -> Type) -> Option<Type> {
+        match self {
+            Type::List(item) => Some(f(*item)),
+            Type::Nullable(inner) => inner
+                .map_outermost_list(f)
+                .map(|inner| Type::Nullable(Box::new(inner))),
+            _ => None,
+        }
+    }
+
+    /// The type juniper resolves in place of this one. Only differs for types juniper doesn't
+    /// support, which have to be converted with `conversion_for_juniper`.
+    fn for_juniper(&self) -> Type {
+        match self {
+            Type::CowStr => Type::Scalar(Either::A(parse_quote! { std::string::String })),
+            Type::Iter(item) => Type::List(Box::new(item.for_juniper())),
+            Type::List(inner) => Type::List(Box::new(inner.for_juniper())),
+            Type::Nullable(inner) => Type::Nullable(Box::new(inner.for_juniper())),
+            other => other.clone(),
+        }
+    }
+
+    /// An expression converting `value` to `self.for_juniper()`, if they're different.
+    fn conversion_for_juniper(&self, value: TokenStream) -> Option<TokenStream> {
+        match self {
+            Type::CowStr => Some(quote! { #value.into_owned() }),
+            Type::Iter(item) => {
+                let item_conversion = item
+                    .conversion_for_juniper(quote! { value })
+                    .map(|conversion| quote! { .map(|value| #conversion) });
+                Some(quote! {
+                    std::iter::IntoIterator::into_iter(#value)
+                        #item_conversion
+                        .collect::<std::vec::Vec<_>>()
+                })
+            }
+            Type::List(item) => item
+                .conversion_for_juniper(quote! { value })
+                .map(|conversion| {
+                    quote! {
+                        std::iter::IntoIterator::into_iter(#value)
+                            .map(|value| #conversion)
+                            .collect::<std::vec::Vec<_>>()
+                    }
+                }),
+            Type::Nullable(inner) => inner
+                .conversion_for_juniper(quote! { value })
+                .map(|conversion| quote! { #value.map(|value| #conversion) }),
+            _ => None,
         }
     }
 
@@ -1134,8 +1628,11 @@ impl Type {
             Type::Object(_) => self,
             Type::Interface { .. } => self,
             Type::Ref(inner) => inner.innermost_type(),
+            Type::Arc(inner) => inner.innermost_type(),
+            Type::Str | Type::CowStr => self,
+            Type::Slice(inner) | Type::Iter(inner) => inner.innermost_type(),
             Type::List(inner) => inner.innermost_type(),
-            Type::Nullable(inner) => inner.innermost_type(),
+            Type::Nullable(inner) | Type::Tristate(inner) => inner.innermost_type(),
         }
     }
 }
@@ -1161,12 +1658,30 @@ impl ToTokens for Type {
             Type::Ref(inner) => {
                 quote! { &#inner }
             }
+            Type::Arc(inner) => {
+                quote! { std::sync::Arc<#inner> }
+            }
+            Type::Str => {
+                quote! { &str }
+            }
+            Type::CowStr => {
+                quote! { std::borrow::Cow<'_, str> }
+            }
+            Type::Slice(inner) => {
+                quote! { &[#inner] }
+            }
+            Type::Iter(inner) => {
+                quote! { impl std::iter::IntoIterator<Item = #inner> }
+            }
             Type::List(inner) => {
                 quote! { std::vec::Vec<#inner> }
             }
             Type::Nullable(inner) => {
                 quote! { std::option::Option<#inner> }
             }
+            Type::Tristate(inner) => {
+                quote! { juniper_from_schema::juniper::Nullable<#inner> }
+            }
         };
         tokens.extend(code);
     }
@@ -1178,32 +1693,72 @@ struct Output {}
 #[derive(Debug)]
 struct Scalar<'doc> {
     name: Ident,
+    graphql_name: &'doc str,
     description: Option<&'doc String>,
+    specified_by: Option<SpecifiedBy>,
+    derives: Vec<syn::Path>,
+    query_trails_module_name: &'doc Ident,
+    visibility: &'doc syn::Visibility,
 }
 
 impl<'doc> ToTokens for Scalar<'doc> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Scalar { name, description } = self;
+        let Scalar {
+            name,
+            graphql_name,
+            description,
+            specified_by,
+            derives,
+            query_trails_module_name,
+            visibility,
+        } = self;
+
+        let serde_attr = if derives_serde(derives) {
+            quote! { #[serde(transparent)] }
+        } else {
+            quote! {}
+        };
 
         let attrs = if let Some(description) = description {
             quote! {
                 #[derive(juniper_from_schema::juniper::GraphQLScalarValue)]
                 #[graphql(
                     transparent,
+                    name = #graphql_name,
                     description = #description,
                 )]
             }
         } else {
             quote! {
                 #[derive(juniper_from_schema::juniper::GraphQLScalarValue)]
-                #[graphql(transparent)]
+                #[graphql(transparent, name = #graphql_name)]
             }
         };
 
+        // juniper 0.15 doesn't support `specified_by_url` so the URL only goes in the docs
+        let mut doc = RustDoc::new();
+        if description.is_some() {
+            doc.push_description(*description);
+            if let Some(specified_by) = specified_by {
+                doc.push(format!("Specified by <{}>", specified_by.url));
+            }
+        }
+
         let code = quote! {
-            #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Hash)]
+            #[derive(
+                std::fmt::Debug,
+                std::cmp::Eq,
+                std::cmp::PartialEq,
+                std::cmp::Ord,
+                std::cmp::PartialOrd,
+                std::clone::Clone,
+                std::hash::Hash,
+                #(#derives,)*
+            )]
+            #doc
             #attrs
-            pub struct #name(pub std::string::String);
+            #serde_attr
+            #visibility struct #name(pub std::string::String);
 
             impl #name {
                 pub fn new<S>(s: S) -> Self
@@ -1226,11 +1781,11 @@ impl<'doc> ToTokens for Scalar<'doc> {
                 }
             }
 
-            impl<'a, 'b> query_trails::FromLookAheadValue<#name>
+            impl<'a, 'b> self::#query_trails_module_name::FromLookAheadValue<#name>
                 for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
             {
                 fn from(self) -> #name {
-                    let s = query_trails::FromLookAheadValue::<String>::from(self);
+                    let s = self::#query_trails_module_name::FromLookAheadValue::<std::string::String>::from(self);
                     #name(s)
                 }
             }
@@ -1242,24 +1797,33 @@ impl<'doc> ToTokens for Scalar<'doc> {
 
 #[derive(Debug)]
 struct Object<'doc> {
-    name: Ident,
+    name: TypeName,
+    has_lifetime: bool,
+    graphql_name: &'doc str,
+    fields_trait_name: Ident,
     description: Option<&'doc String>,
     context_type: &'doc syn::Type,
+    visibility: &'doc syn::Visibility,
     fields: Vec<Field<'doc>>,
-    implements_interfaces: Vec<Ident>,
+    implements_interfaces: Vec<TypeName>,
 }
 
 impl<'doc> ToTokens for Object<'doc> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Object {
             name,
+            has_lifetime,
+            graphql_name,
+            fields_trait_name: trait_name,
             context_type,
+            visibility,
             description,
             fields,
             implements_interfaces,
         } = self;
 
         let mut graphql_attrs = GraphqlAttr::new_object();
+        graphql_attrs.push_key_value(format_ident!("name"), graphql_name);
 
         if let Some(description) = description {
             graphql_attrs.push_key_value(format_ident!("description"), description);
@@ -1278,47 +1842,58 @@ impl<'doc> ToTokens for Object<'doc> {
             );
         }
 
-        let trait_name = fields_trait_name(name);
+        let mut doc = RustDoc::new();
+        doc.push_description(*description);
 
         let fields_for_impl = fields
             .iter()
-            .map(|field| field.to_tokens_for_graphql_object_impl(&trait_name));
+            .map(|field| field.to_tokens_for_graphql_object_impl(trait_name));
 
         let fields_for_trait = fields.iter().map(|field| field.to_tokens_for_trait());
 
+        let args_structs = fields
+            .iter()
+            .filter_map(|field| field.args_struct_definition(trait_name, visibility));
+
         let async_trait_attr = if fields.iter().any(|f| f.directives.r#async.value) {
             Some(quote! { #[juniper_from_schema::juniper::async_trait] })
         } else {
             None
         };
 
+        let generics = lifetime_generics(*has_lifetime);
+
         let code = quote! {
             #graphql_attrs
-            impl #name {
+            impl #generics #name {
                 #(#fields_for_impl)*
             }
 
+            #doc
             #async_trait_attr
-            pub trait #trait_name {
+            #visibility trait #trait_name {
                 #(#fields_for_trait)*
             }
+
+            #(#args_structs)*
         };
 
         tokens.extend(code);
     }
 }
 
-fn fields_trait_name(name: &Ident) -> Ident {
-    format_ident!("{}Fields", name)
-}
-
 #[derive(Debug)]
 struct Field<'doc> {
     description: Option<&'doc String>,
     name: Ident,
-    error_type: &'doc syn::Type,
+    graphql_name: &'doc str,
+    trait_field_name: Ident,
+    error_type: syn::Type,
     context_type: &'doc syn::Type,
     args: Vec<FieldArg<'doc>>,
+    /// The struct the arguments are passed to the trait method in, if `args_struct` is enabled.
+    /// No struct is generated for interface fields since the implementors' structs are used.
+    args_struct: Option<Ident>,
     return_type: Type,
     directives: FieldDirectives,
 }
@@ -1344,10 +1919,12 @@ impl<'doc> Field<'doc> {
 
     fn to_tokens_for_interface_impl<'a>(
         &'a self,
+        implementor: &'a Ident,
         trait_name: &'a Ident,
     ) -> FieldToTokensInterfaceImpl<'a, 'doc> {
         FieldToTokensInterfaceImpl {
             field: self,
+            implementor,
             trait_name,
         }
     }
@@ -1369,7 +1946,106 @@ impl<'doc> Field<'doc> {
     }
 
     fn trait_field_name(&self) -> Ident {
-        format_ident!("field_{}", self.name)
+        self.trait_field_name.clone()
+    }
+
+    /// The lifetimes and executor parameter of the trait method. Fields can opt out of taking the
+    /// executor with `@juniper(executor: false)`.
+    fn executor_param(&self) -> (TokenStream, Option<TokenStream>) {
+        if self.directives.executor.value {
+            let context_type = self.context_type;
+            (
+                quote! { 's, 'r, 'a },
+                Some(quote! {
+                    executor: &juniper_from_schema::juniper::Executor<'r, 'a, #context_type>,
+                }),
+            )
+        } else {
+            (quote! { 's, 'r }, None)
+        }
+    }
+
+    fn executor_arg(&self) -> Option<TokenStream> {
+        if self.directives.executor.value {
+            Some(quote! { executor, })
+        } else {
+            None
+        }
+    }
+
+    /// juniper still passes the executor to the generated resolver, which otherwise only uses it
+    /// to build the query trail.
+    fn discard_unused_executor(&self) -> Option<TokenStream> {
+        if self.directives.executor.value || self.query_trail_param().is_some() {
+            None
+        } else {
+            Some(quote! { let _ = executor; })
+        }
+    }
+
+    /// The parameters of the trait method for the field's arguments.
+    fn trait_method_params(&self) -> TokenStream {
+        match &self.args_struct {
+            Some(args_struct) => quote! { args: #args_struct, },
+            None => {
+                let args = self.args.iter().map(|arg| arg.to_tokens_for_trait());
+                quote! { #(#args,)* }
+            }
+        }
+    }
+
+    /// The field's arguments passed on to the trait method, which must have been rebound with
+    /// their default values.
+    fn trait_method_args(&self, args_struct: Option<&Ident>) -> TokenStream {
+        let arg_names = self.args.iter().map(|arg| &arg.name);
+
+        match args_struct {
+            Some(args_struct) => quote! { #args_struct { #(#arg_names),* }, },
+            None => quote! { #(#arg_names,)* },
+        }
+    }
+
+    fn args_struct_definition(
+        &self,
+        trait_name: &Ident,
+        visibility: &syn::Visibility,
+    ) -> Option<TokenStream> {
+        let args_struct = self.args_struct.as_ref()?;
+
+        let fields = self.args.iter().map(|arg| {
+            let mut doc = RustDoc::new();
+            doc.push_description(arg.description);
+            doc.push_deprecation(Some(&arg.deprecation));
+            doc.push_default_value(arg.default_value_graphql.as_ref());
+
+            let name = &arg.name;
+            let ty = arg.ty_with_default_applied();
+            quote! {
+                #doc
+                pub #name: #ty
+            }
+        });
+
+        let doc = format!(
+            " The arguments of `{}::{}`.",
+            trait_name, self.trait_field_name
+        );
+
+        Some(quote! {
+            #[doc = #doc]
+            #[derive(std::clone::Clone, std::fmt::Debug)]
+            #visibility struct #args_struct {
+                #(#fields,)*
+            }
+        })
+    }
+
+    fn rust_doc(&self) -> RustDoc {
+        let mut doc = RustDoc::new();
+        doc.push_description(self.description);
+        doc.push_deprecation(self.directives.deprecated.as_ref());
+        doc.push_arguments(&self.args);
+        doc
     }
 
     fn asyncness(&self) -> Option<Token![async]> {
@@ -1398,8 +2074,13 @@ impl<'doc> Field<'doc> {
             Ownership::Borrowed => {
                 parse_quote! { &#return_type }
             }
-            Ownership::AsRef => {
-                // this case is handled in `graphql_type_to_rust_type`
+            Ownership::AsRef
+            | Ownership::Arc
+            | Ownership::Str
+            | Ownership::Cow
+            | Ownership::Slice
+            | Ownership::Iter => {
+                // these cases are handled when the return type is built
                 parse_quote! { #return_type }
             }
         }
@@ -1413,6 +2094,35 @@ impl<'doc> Field<'doc> {
         )
     }
 
+    /// The return type of the resolver juniper calls, which might differ from the trait method's
+    /// if juniper can't resolve what the trait method returns.
+    fn juniper_return_type(&self) -> syn::Type {
+        if self.return_type.conversion_for_juniper(quote! {}).is_none() {
+            return self.full_return_type();
+        }
+
+        let return_type = self.return_type.for_juniper();
+        maybe_wrap_final_return_type_in_result(
+            parse_quote! { #return_type },
+            &self.error_type,
+            &self.directives,
+        )
+    }
+
+    /// Convert the value returned by the trait method to `juniper_return_type`.
+    fn convert_for_juniper(&self, returned: TokenStream) -> TokenStream {
+        match self.return_type.conversion_for_juniper(quote! { value }) {
+            None => returned,
+            Some(conversion) if self.directives.infallible.value => quote! {
+                {
+                    let value = #returned;
+                    #conversion
+                }
+            },
+            Some(conversion) => quote! { #returned.map(|value| #conversion) },
+        }
+    }
+
     fn full_stream_return_type(&self) -> syn::Type {
         let default_return_type = || {
             let item_type = self.stream_item_type();
@@ -1452,8 +2162,13 @@ impl<'doc> Field<'doc> {
         }
     }
 
-    fn query_trail_type(&self) -> &Type {
-        self.return_type.innermost_type()
+    fn query_trail_type(&self) -> TokenStream {
+        match self.return_type.innermost_type() {
+            Type::Object(name) | Type::Interface(name) | Type::Union(name) => {
+                name.for_query_trail().into_token_stream()
+            }
+            other => other.into_token_stream(),
+        }
     }
 
     fn query_trail_param(&self) -> Option<TokenStream> {
@@ -1481,7 +2196,10 @@ impl<'a, 'doc> ToTokens for FieldToTokensGraphqlObject<'a, 'doc> {
         let Field {
             description,
             name,
+            graphql_name,
+            trait_field_name: _,
             error_type: _,
+            args_struct: _,
             context_type: _,
             args,
             return_type: _,
@@ -1489,19 +2207,12 @@ impl<'a, 'doc> ToTokens for FieldToTokensGraphqlObject<'a, 'doc> {
         } = self.field;
 
         let mut graphql_attrs = GraphqlAttr::new();
+        graphql_attrs.push_key_value(format_ident!("name"), graphql_name);
 
         if !args.is_empty() {
-            let parts = args.iter().filter_map(|arg| {
-                let name = &arg.name_without_raw_ident;
-
-                if let Some(description) = &arg.description {
-                    Some(quote! {
-                        #name(description = #description)
-                    })
-                } else {
-                    None
-                }
-            });
+            let parts = args
+                .iter()
+                .filter_map(|arg| arg.graphql_attr_for_arguments());
 
             graphql_attrs.push_fn(format_ident!("arguments"), parts);
         };
@@ -1514,8 +2225,10 @@ impl<'a, 'doc> ToTokens for FieldToTokensGraphqlObject<'a, 'doc> {
 
         let trait_name = self.trait_name;
         let trait_field_name = self.field.trait_field_name();
-        let arg_names = args.iter().map(|arg| &arg.name);
-        let return_type = self.field.full_return_type();
+        let trait_method_args = self
+            .field
+            .trait_method_args(self.field.args_struct.as_ref());
+        let return_type = self.field.juniper_return_type();
 
         let args_for_signature = args
             .iter()
@@ -1542,9 +2255,20 @@ impl<'a, 'doc> ToTokens for FieldToTokensGraphqlObject<'a, 'doc> {
             quote! {}
         };
 
+        let executor_arg = self.field.executor_arg();
+        let discard_executor = self.field.discard_unused_executor();
         let asyncness = self.field.asyncness();
         let awaitness = self.field.awaitness();
 
+        let resolved_value = self.field.convert_for_juniper(quote! {
+            <Self as #trait_name>::#trait_field_name(
+                self,
+                #executor_arg
+                #query_trail_arg
+                #trait_method_args
+            ) #awaitness
+        });
+
         tokens.extend(quote! {
             #graphql_attrs
             #asyncness fn #name(
@@ -1552,13 +2276,9 @@ impl<'a, 'doc> ToTokens for FieldToTokensGraphqlObject<'a, 'doc> {
                 executor: &Executor,
                 #(#args_for_signature,)*
             ) -> #return_type {
+                #discard_executor
                 #(#rebind_args_with_default_values)*
-                <Self as #trait_name>::#trait_field_name(
-                    self,
-                    executor,
-                    #query_trail_arg
-                    #(#arg_names,)*
-                ) #awaitness
+                #resolved_value
             }
         });
     }
@@ -1574,28 +2294,34 @@ impl<'a, 'doc> ToTokens for FieldToTokensTrait<'a, 'doc> {
         let Field {
             description: _,
             name: _,
+            graphql_name: _,
+            trait_field_name: _,
             error_type: _,
-            context_type,
-            args,
+            args_struct: _,
+            context_type: _,
+            args: _,
             return_type: _,
             directives: _,
         } = self.field;
 
         let name = self.field.trait_field_name();
+        let (lifetimes, executor_param) = self.field.executor_param();
         let full_return_type = self.field.full_return_type();
 
-        let args = args.iter().map(|arg| arg.to_tokens_for_trait());
+        let params = self.field.trait_method_params();
 
         let query_trail_param = self.field.query_trail_param();
 
         let asyncness = self.field.asyncness();
+        let doc = self.field.rust_doc();
 
         tokens.extend(quote! {
-            #asyncness fn #name<'s, 'r, 'a>(
+            #doc
+            #asyncness fn #name<#lifetimes>(
                 &'s self,
-                executor: &juniper_from_schema::juniper::Executor<'r, 'a, #context_type>,
+                #executor_param
                 #query_trail_param
-                #(#args,)*
+                #params
             ) -> #full_return_type;
         });
     }
@@ -1611,18 +2337,22 @@ impl<'a, 'doc> ToTokens for FieldToTokensInterface<'a, 'doc> {
         let Field {
             description,
             name,
+            graphql_name,
+            trait_field_name: _,
             error_type: _,
+            args_struct: _,
             context_type,
             args,
             return_type: _,
             directives,
         } = self.field;
 
-        let return_type = self.field.full_return_type();
+        let return_type = self.field.juniper_return_type();
 
         let args = args.iter().map(|arg| arg.to_tokens_for_interface());
 
         let mut graphql_attrs = GraphqlAttr::new();
+        graphql_attrs.push_key_value(format_ident!("name"), graphql_name);
 
         if let Some(desc) = description {
             graphql_attrs.push_key_value(format_ident!("description"), desc);
@@ -1632,7 +2362,14 @@ impl<'a, 'doc> ToTokens for FieldToTokensInterface<'a, 'doc> {
 
         let asyncness = self.field.asyncness();
 
+        let doc = if description.is_some() {
+            self.field.rust_doc()
+        } else {
+            RustDoc::new()
+        };
+
         tokens.extend(quote! {
+            #doc
             #graphql_attrs
             #asyncness fn #name<'s, 'r, 'a>(
                 &'s self,
@@ -1651,6 +2388,7 @@ impl<'a, 'doc> ToTokens for FieldToTokensInterface<'a, 'doc> {
 #[derive(Debug)]
 struct FieldToTokensInterfaceImpl<'a, 'doc> {
     field: &'a Field<'doc>,
+    implementor: &'a Ident,
     trait_name: &'a Ident,
 }
 
@@ -1661,20 +2399,30 @@ impl<'a, 'doc> ToTokens for FieldToTokensInterfaceImpl<'a, 'doc> {
                 Field {
                     description: _,
                     name,
+                    graphql_name: _,
+                    trait_field_name: _,
                     error_type: _,
+                    args_struct: _,
                     context_type,
                     args,
                     return_type: _,
                     directives: _,
                 },
+            implementor,
             trait_name,
         } = self;
 
         // TODO: Remove duplication between this and the object version
 
         let trait_field_name = self.field.trait_field_name();
-        let arg_names = args.iter().map(|arg| &arg.name);
-        let full_return_type = self.field.full_return_type();
+        // The implementor's fields trait takes its own args struct
+        let args_struct = self
+            .field
+            .args_struct
+            .as_ref()
+            .map(|_| args_struct_name(implementor, name));
+        let trait_method_args = self.field.trait_method_args(args_struct.as_ref());
+        let full_return_type = self.field.juniper_return_type();
 
         // juniper doesn't supporte descriptions on interface field arguments so we cannot add
         // those
@@ -1704,9 +2452,20 @@ impl<'a, 'doc> ToTokens for FieldToTokensInterfaceImpl<'a, 'doc> {
             quote! {}
         };
 
+        let executor_arg = self.field.executor_arg();
+        let discard_executor = self.field.discard_unused_executor();
         let asyncness = self.field.asyncness();
         let awaitness = self.field.awaitness();
 
+        let resolved_value = self.field.convert_for_juniper(quote! {
+            <Self as #trait_name>::#trait_field_name(
+                self,
+                #executor_arg
+                #query_trail_arg
+                #trait_method_args
+            ) #awaitness
+        });
+
         let code = quote! {
             #asyncness fn #name<'s, 'r, 'a>(
                 &'s self,
@@ -1718,13 +2477,9 @@ impl<'a, 'doc> ToTokens for FieldToTokensInterfaceImpl<'a, 'doc> {
                 >,
                 #(#args_for_signature),*
             ) -> #full_return_type {
+                #discard_executor
                 #(#rebind_args_with_default_values)*
-                <Self as #trait_name>::#trait_field_name(
-                    self,
-                    executor,
-                    #query_trail_arg
-                    #(#arg_names,)*
-                ) #awaitness
+                #resolved_value
             }
         };
         tokens.extend(code)
@@ -1742,38 +2497,36 @@ impl<'a, 'doc> ToTokens for FieldToTokensForSubscriptionImpl<'a, 'doc> {
         let Field {
             description,
             name,
+            graphql_name,
+            trait_field_name: _,
             args,
             error_type: _,
+            args_struct: _,
             context_type: _,
             return_type: _,
             directives: _,
         } = self.field;
 
         let mut graphql_attrs = GraphqlAttr::new();
+        graphql_attrs.push_key_value(format_ident!("name"), graphql_name);
 
         if let Some(description) = description {
             graphql_attrs.push_key_value(format_ident!("description"), description);
         };
 
         if !args.is_empty() {
-            let parts = args.iter().filter_map(|arg| {
-                let name = &arg.name_without_raw_ident;
-
-                if let Some(description) = &arg.description {
-                    Some(quote! {
-                        #name(description = #description)
-                    })
-                } else {
-                    None
-                }
-            });
+            let parts = args
+                .iter()
+                .filter_map(|arg| arg.graphql_attr_for_arguments());
 
             graphql_attrs.push_fn(format_ident!("arguments"), parts);
         };
 
         let trait_name = self.trait_name;
         let trait_field_name = self.field.trait_field_name();
-        let arg_names = args.iter().map(|arg| &arg.name);
+        let trait_method_args = self
+            .field
+            .trait_method_args(self.field.args_struct.as_ref());
 
         let return_type = self.field.full_stream_return_type();
 
@@ -1802,6 +2555,8 @@ impl<'a, 'doc> ToTokens for FieldToTokensForSubscriptionImpl<'a, 'doc> {
             quote! {}
         };
 
+        let executor_arg = self.field.executor_arg();
+        let discard_executor = self.field.discard_unused_executor();
         let awaitness = self.field.awaitness();
 
         let tryness = if self.field.directives.infallible.value {
@@ -1817,7 +2572,7 @@ impl<'a, 'doc> ToTokens for FieldToTokensForSubscriptionImpl<'a, 'doc> {
         };
 
         if !self.field.directives.infallible.value {
-            return_result = quote! { Ok(#return_result) };
+            return_result = quote! { std::result::Result::Ok(#return_result) };
         }
 
         let code = quote! {
@@ -1826,12 +2581,13 @@ impl<'a, 'doc> ToTokens for FieldToTokensForSubscriptionImpl<'a, 'doc> {
                 executor: &Executor,
                 #(#args_for_signature,)*
             ) -> #return_type {
+                #discard_executor
                 #(#rebind_args_with_default_values)*
                 let resolved_value = <Self as #trait_name>::#trait_field_name(
                     self,
-                    executor,
+                    #executor_arg
                     #query_trail_arg
-                    #(#arg_names,)*
+                    #trait_method_args
                 ) #awaitness #tryness;
                 #return_result
             }
@@ -1849,27 +2605,33 @@ struct FieldToTokensForSubscriptionTrait<'a, 'doc> {
 impl<'a, 'doc> ToTokens for FieldToTokensForSubscriptionTrait<'a, 'doc> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Field {
-            context_type,
-            args,
+            context_type: _,
+            args: _,
             description: _,
             name: _,
+            graphql_name: _,
+            trait_field_name: _,
             error_type: _,
+            args_struct: _,
             return_type: _,
             directives: _,
         } = self.field;
 
         let name = self.field.trait_field_name();
-        let args = args.iter().map(|arg| arg.to_tokens_for_trait());
+        let (lifetimes, executor_param) = self.field.executor_param();
+        let params = self.field.trait_method_params();
         let query_trail_param = self.field.query_trail_param();
         let asyncness = self.field.asyncness();
         let return_type = self.field.full_stream_return_type();
+        let doc = self.field.rust_doc();
 
         tokens.extend(quote! {
-            #asyncness fn #name<'s, 'r, 'a>(
+            #doc
+            #asyncness fn #name<#lifetimes>(
                 &'s self,
-                executor: &juniper_from_schema::juniper::Executor<'r, 'a, #context_type>,
+                #executor_param
                 #query_trail_param
-                #(#args,)*
+                #params
             ) -> #return_type;
         });
     }
@@ -1879,9 +2641,12 @@ impl<'a, 'doc> ToTokens for FieldToTokensForSubscriptionTrait<'a, 'doc> {
 struct FieldArg<'doc> {
     name: Ident,
     name_without_raw_ident: Ident,
+    renamed_from: Option<&'doc str>,
     description: Option<&'doc String>,
     ty: Type,
     default_value: Option<TokenStream>,
+    default_value_graphql: Option<String>,
+    deprecation: Deprecation,
 }
 
 impl<'doc> FieldArg<'doc> {
@@ -1896,6 +2661,32 @@ impl<'doc> FieldArg<'doc> {
     fn to_tokens_for_interface<'a>(&'a self) -> FieldArgsToTokensInterface<'a, 'doc> {
         FieldArgsToTokensInterface(self)
     }
+
+    /// The type of the argument once its default value, if any, has been applied.
+    fn ty_with_default_applied(&self) -> &Type {
+        if self.default_value.is_some() {
+            self.ty.remove_one_layer_of_nullability()
+        } else {
+            &self.ty
+        }
+    }
+
+    fn graphql_attr_for_arguments(&self) -> Option<TokenStream> {
+        let mut parts = Vec::new();
+        if let Some(graphql_name) = self.renamed_from {
+            parts.push(quote! { name = #graphql_name });
+        }
+        if let Some(description) = self.description {
+            parts.push(quote! { description = #description });
+        }
+
+        if parts.is_empty() {
+            None
+        } else {
+            let name = &self.name_without_raw_ident;
+            Some(quote! { #name(#(#parts),*) })
+        }
+    }
 }
 
 struct FieldArgToTokensGraphqlObject<'a, 'doc>(&'a FieldArg<'doc>);
@@ -1905,9 +2696,12 @@ impl<'a, 'doc> ToTokens for FieldArgToTokensGraphqlObject<'a, 'doc> {
         let FieldArg {
             name,
             name_without_raw_ident: _,
+            renamed_from: _,
             description: _,
             ty,
             default_value: _,
+            default_value_graphql: _,
+            deprecation: _,
         } = self.0;
 
         tokens.extend(quote! {
@@ -1923,16 +2717,15 @@ impl<'a, 'doc> ToTokens for FieldArgsToTokensTrait<'a, 'doc> {
         let FieldArg {
             name,
             name_without_raw_ident: _,
+            renamed_from: _,
             description: _,
-            ty,
-            default_value,
+            ty: _,
+            default_value: _,
+            default_value_graphql: _,
+            deprecation: _,
         } = self.0;
 
-        let ty = if default_value.is_some() {
-            ty.remove_one_layer_of_nullability()
-        } else {
-            ty
-        };
+        let ty = self.0.ty_with_default_applied();
 
         tokens.extend(quote! {
             #name: #ty
@@ -1948,11 +2741,19 @@ impl<'a, 'doc> ToTokens for FieldArgsToTokensInterface<'a, 'doc> {
             name,
             description: _,
             name_without_raw_ident: _,
+            renamed_from,
             ty,
             default_value: _,
+            default_value_graphql: _,
+            deprecation: _,
         } = self.0;
 
+        let graphql_attr = renamed_from.map(|graphql_name| {
+            quote! { #[graphql(name = #graphql_name)] }
+        });
+
         tokens.extend(quote! {
+            #graphql_attr
             #name: #ty
         });
     }
@@ -1960,9 +2761,12 @@ impl<'a, 'doc> ToTokens for FieldArgsToTokensInterface<'a, 'doc> {
 
 #[derive(Debug)]
 struct Subscription<'doc> {
-    name: Ident,
+    name: TypeName,
+    graphql_name: &'doc str,
+    fields_trait_name: Ident,
     description: Option<&'doc String>,
     context_type: &'doc syn::Type,
+    visibility: &'doc syn::Visibility,
     fields: Vec<Field<'doc>>,
 }
 
@@ -1970,12 +2774,16 @@ impl<'doc> ToTokens for Subscription<'doc> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Subscription {
             name,
+            graphql_name,
+            fields_trait_name: trait_name,
             description,
             context_type,
+            visibility,
             fields,
         } = self;
 
         let mut graphql_attrs = GraphqlAttr::new_subscription();
+        graphql_attrs.push_key_value(format_ident!("name"), graphql_name);
         graphql_attrs.push_key_value(format_ident!("Context"), context_type);
 
         if let Some(description) = description {
@@ -1986,16 +2794,21 @@ impl<'doc> ToTokens for Subscription<'doc> {
             quote! { juniper_from_schema::juniper::DefaultScalarValue },
         );
 
-        let trait_name = fields_trait_name(name);
+        let mut doc = RustDoc::new();
+        doc.push_description(*description);
 
         let fields_for_impl = fields
             .iter()
-            .map(|field| field.to_tokens_for_subscription_impl(&trait_name));
+            .map(|field| field.to_tokens_for_subscription_impl(trait_name));
 
         let fields_for_trait = fields
             .iter()
             .map(|field| field.to_tokens_for_subscription_trait());
 
+        let args_structs = fields
+            .iter()
+            .filter_map(|field| field.args_struct_definition(trait_name, visibility));
+
         let async_trait_attr = if fields.iter().any(|f| f.directives.r#async.value) {
             Some(quote! { #[juniper_from_schema::juniper::async_trait] })
         } else {
@@ -2008,10 +2821,13 @@ impl<'doc> ToTokens for Subscription<'doc> {
                 #(#fields_for_impl)*
             }
 
+            #doc
             #async_trait_attr
-            pub trait #trait_name {
+            #visibility trait #trait_name {
                 #(#fields_for_trait)*
             }
+
+            #(#args_structs)*
         });
     }
 }
@@ -2020,10 +2836,14 @@ impl<'doc> ToTokens for Subscription<'doc> {
 struct Interface<'doc> {
     description: Option<&'doc String>,
     name: Ident,
+    graphql_name: &'doc str,
     trait_name: Ident,
+    has_lifetime: bool,
     fields: Vec<Field<'doc>>,
-    implementors: Vec<Ident>,
+    implementors: Vec<TypeName>,
+    implementor_fields_trait_names: Vec<Ident>,
     context_type: &'doc syn::Type,
+    visibility: &'doc syn::Visibility,
 }
 
 impl<'doc> ToTokens for Interface<'doc> {
@@ -2031,9 +2851,13 @@ impl<'doc> ToTokens for Interface<'doc> {
         let Interface {
             description,
             name,
+            graphql_name,
             trait_name: interface_trait_name,
+            has_lifetime,
             implementors,
+            implementor_fields_trait_names,
             context_type,
+            visibility,
             fields,
         } = self;
 
@@ -2046,8 +2870,7 @@ impl<'doc> ToTokens for Interface<'doc> {
         );
         graphql_attrs.push_key_value(format_ident!("enum"), name);
 
-        let name_lit = syn::LitStr::new(&name.to_string(), proc_macro2::Span::call_site());
-        graphql_attrs.push_key_value(format_ident!("name"), name_lit);
+        graphql_attrs.push_key_value(format_ident!("name"), graphql_name);
 
         if let Some(description) = description {
             graphql_attrs.push_key_value(format_ident!("description"), description);
@@ -2055,25 +2878,29 @@ impl<'doc> ToTokens for Interface<'doc> {
 
         let fields_for_impl = fields.iter().map(|field| field.to_tokens_for_interface());
 
+        let mut doc = RustDoc::new();
+        doc.push_description(*description);
+
+        let generics = lifetime_generics(*has_lifetime);
+
         tokens.extend(quote! {
+            #doc
             #graphql_attrs
-            pub trait #interface_trait_name {
+            #visibility trait #interface_trait_name #generics {
                 #(#fields_for_impl)*
             }
         });
 
-        for implementor in implementors {
-            let trait_name = fields_trait_name(implementor);
-
+        for (implementor, trait_name) in implementors.iter().zip(implementor_fields_trait_names) {
             let fields_for_impl = fields
                 .iter()
-                .map(|field| field.to_tokens_for_interface_impl(&trait_name));
+                .map(|field| field.to_tokens_for_interface_impl(&implementor.ident, trait_name));
 
             let graphql_attr = GraphqlAttr::new_interface_top_level();
 
             tokens.extend(quote! {
                 #graphql_attr
-                impl #interface_trait_name for #implementor {
+                impl #generics #interface_trait_name #generics for #implementor {
                     #(#fields_for_impl)*
                 }
             })
@@ -2084,21 +2911,28 @@ impl<'doc> ToTokens for Interface<'doc> {
 #[derive(Debug)]
 struct Union<'doc> {
     name: Ident,
+    has_lifetime: bool,
+    graphql_name: &'doc str,
     variants: Vec<UnionVariant>,
     description: Option<&'doc String>,
     context_type: &'doc syn::Type,
+    visibility: &'doc syn::Visibility,
 }
 
 impl<'doc> ToTokens for Union<'doc> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Union {
             name,
+            has_lifetime,
+            graphql_name,
             variants,
             description,
             context_type,
+            visibility,
         } = self;
 
         let mut graphql_attrs = GraphqlAttr::new();
+        graphql_attrs.push_key_value(format_ident!("name"), graphql_name);
         graphql_attrs.push_key_value(format_ident!("Context"), context_type);
         graphql_attrs.push_key_value(
             format_ident!("Scalar"),
@@ -2109,22 +2943,28 @@ impl<'doc> ToTokens for Union<'doc> {
             graphql_attrs.push_key_value(format_ident!("description"), description);
         }
 
+        let generics = lifetime_generics(*has_lifetime);
+
         let from_impls = variants.iter().map(|variant| {
             let inner_ty = &variant.type_inside;
             let rust_variant = &variant.rust_name;
             quote! {
-                impl std::convert::From<#inner_ty> for #name {
-                    fn from(inner: #inner_ty) -> #name {
+                impl #generics std::convert::From<#inner_ty> for #name #generics {
+                    fn from(inner: #inner_ty) -> #name #generics {
                         #name::#rust_variant(inner)
                     }
                 }
             }
         });
 
+        let mut doc = RustDoc::new();
+        doc.push_description(*description);
+
         tokens.extend(quote! {
+            #doc
             #[derive(juniper_from_schema::juniper::GraphQLUnion)]
             #graphql_attrs
-            pub enum #name {
+            #visibility enum #name #generics {
                 #(#variants,)*
             }
 
@@ -2135,7 +2975,6 @@ impl<'doc> ToTokens for Union<'doc> {
 
 #[derive(Debug)]
 struct UnionVariant {
-    graphql_name: Ident,
     rust_name: Ident,
     type_inside: Box<Type>,
 }
@@ -2143,7 +2982,6 @@ struct UnionVariant {
 impl ToTokens for UnionVariant {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let UnionVariant {
-            graphql_name: _,
             rust_name,
             type_inside,
         } = self;
@@ -2157,68 +2995,134 @@ impl ToTokens for UnionVariant {
 #[derive(Debug)]
 struct Enum<'doc> {
     name: Ident,
+    /// The existing type the enum is mapped onto with `@juniper(rust_type: "...")`.
+    rust_type: Option<syn::Path>,
+    graphql_name: &'doc str,
     variants: Vec<EnumVariant<'doc>>,
+    derives: Vec<syn::Path>,
     description: Option<&'doc String>,
+    query_trails_module_name: &'doc Ident,
+    visibility: &'doc syn::Visibility,
 }
 
 impl<'doc> ToTokens for Enum<'doc> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Enum {
             name,
+            rust_type,
+            derives,
+            graphql_name,
             variants,
             description,
+            query_trails_module_name,
+            visibility,
         } = self;
 
-        let graphql_attr = description.map(|description| {
-            quote! {
-                #[graphql(description=#description)]
-            }
-        });
+        let mut graphql_attr = GraphqlAttr::new();
+        graphql_attr.push_key_value(format_ident!("name"), graphql_name);
+        if let Some(description) = description {
+            graphql_attr.push_key_value(format_ident!("description"), description);
+        }
+
+        // With `rust_type` juniper's traits are derived for a hidden enum that values are converted
+        // through, and the existing type is used everywhere else
+        let (name, rust_type) = match rust_type {
+            Some(rust_type) => (format_ident!("__{}", name), quote! { #rust_type }),
+            None => (name.clone(), quote! { #name }),
+        };
 
         let string_to_enum_value_mappings = variants.iter().map(|variant| {
             let graphql_name = variant.graphql_name;
             let variant_name = &variant.name;
-            quote! { &#graphql_name => #name::#variant_name }
+            quote! { &#graphql_name => #rust_type::#variant_name }
         });
 
+        let mut doc = RustDoc::new();
+        doc.push_description(*description);
+
+        if self.rust_type.is_some() {
+            let variant_names = variants
+                .iter()
+                .map(|variant| &variant.name)
+                .collect::<Vec<_>>();
+
+            doc = RustDoc::new();
+            doc.push(format!(
+                "Glue for mapping the GraphQL enum `{}` onto an existing type.",
+                graphql_name
+            ));
+            doc.push("Generated by `juniper-from-schema`.");
+
+            let to_input_value = quote! {
+                juniper_from_schema::juniper::ToInputValue::to_input_value(&#name::from(self))
+            };
+            let juniper_impls =
+                mapped_type_juniper_impls(&rust_type, &name, to_input_value, true, &[]);
+
+            tokens.extend(quote! {
+                impl std::convert::From<#name> for #rust_type {
+                    fn from(value: #name) -> #rust_type {
+                        match value {
+                            #(#name::#variant_names => #rust_type::#variant_names,)*
+                        }
+                    }
+                }
+
+                impl<'a> std::convert::From<&'a #rust_type> for #name {
+                    fn from(value: &'a #rust_type) -> #name {
+                        match value {
+                            #(#rust_type::#variant_names => #name::#variant_names,)*
+                        }
+                    }
+                }
+
+                #juniper_impls
+            });
+        }
+
+        let hidden = self.rust_type.as_ref().map(|_| quote! { #[doc(hidden)] });
+
         tokens.extend(quote! {
             #[derive(
                 juniper_from_schema::juniper::GraphQLEnum,
-                Debug,
-                Eq,
-                PartialEq,
-                Ord,
-                PartialOrd,
-                Copy,
-                Clone,
-                Hash,
+                std::fmt::Debug,
+                std::cmp::Eq,
+                std::cmp::PartialEq,
+                std::cmp::Ord,
+                std::cmp::PartialOrd,
+                std::marker::Copy,
+                std::clone::Clone,
+                std::hash::Hash,
+                #(#derives,)*
             )]
+            #doc
+            #hidden
             #graphql_attr
-            pub enum #name {
+            #visibility enum #name {
                 #(#variants),*
             }
 
-            impl<'a, 'b> query_trails::FromLookAheadValue<#name>
+            impl<'a, 'b> self::#query_trails_module_name::FromLookAheadValue<#rust_type>
                 for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
             {
-                fn from(self) -> #name {
+                fn from(self) -> #rust_type {
                     match self {
                         juniper_from_schema::juniper::LookAheadValue::Enum(name) => {
                             match name {
                                 #(#string_to_enum_value_mappings,)*
-                                other => panic!("Invalid enum name: {}", other),
+                                other => std::panic!("Invalid enum name: {}", other),
                             }
                         },
-                        juniper_from_schema::juniper::LookAheadValue::Null => panic!(
+                        juniper_from_schema::juniper::LookAheadValue::Null => std::panic!(
                             "Failed converting look ahead value. Expected enum type got `null`",
                         ),
-                        juniper_from_schema::juniper::LookAheadValue::List(_) => panic!(
+                        juniper_from_schema::juniper::LookAheadValue::List(_) => std::panic!(
                             "Failed converting look ahead value. Expected enum type got `list`",
                         ),
-                        juniper_from_schema::juniper::LookAheadValue::Object(_) => panic!(
+                        juniper_from_schema::juniper::LookAheadValue::Object(_) => std::panic!(
                             "Failed converting look ahead value. Expected enum type got `object`",
                         ),
-                        juniper_from_schema::juniper::LookAheadValue::Scalar(_) => panic!(
+                        juniper_from_schema::juniper::LookAheadValue::Scalar(_) => std::panic!(
                             "Failed converting look ahead value. Expected enum type got `scalar`",
                         ),
                     }
@@ -2234,6 +3138,7 @@ struct EnumVariant<'doc> {
     deprecation: Deprecation,
     description: Option<&'doc String>,
     graphql_name: &'doc str,
+    serde: bool,
 }
 
 impl<'doc> ToTokens for EnumVariant<'doc> {
@@ -2243,6 +3148,7 @@ impl<'doc> ToTokens for EnumVariant<'doc> {
             description,
             deprecation,
             graphql_name,
+            serde,
         } = self;
 
         let mut graphql_attrs = GraphqlAttr::new();
@@ -2260,9 +3166,19 @@ impl<'doc> ToTokens for EnumVariant<'doc> {
             graphql_attrs.push_key_value(format_ident!("description"), description);
         }
 
+        let mut doc = RustDoc::new();
+        if description.is_some() {
+            doc.push_description(*description);
+            doc.push_deprecation(Some(deprecation));
+        }
+
+        let serde_attr = serde_rename(*serde, graphql_name);
+
         tokens.extend(quote! {
             #[allow(missing_docs)]
+            #doc
             #graphql_attrs
+            #serde_attr
             #name
         })
     }
@@ -2271,19 +3187,31 @@ impl<'doc> ToTokens for EnumVariant<'doc> {
 #[derive(Debug)]
 struct InputObject<'doc> {
     name: Ident,
+    /// The existing type the input object is mapped onto with `@juniper(rust_type: "...")`.
+    rust_type: Option<syn::Path>,
+    graphql_name: &'doc str,
     description: Option<&'doc String>,
     fields: Vec<InputObjectField<'doc>>,
+    derives: Vec<syn::Path>,
+    query_trails_module_name: &'doc Ident,
+    visibility: &'doc syn::Visibility,
 }
 
 impl<'doc> ToTokens for InputObject<'doc> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let InputObject {
             name,
+            rust_type,
+            derives,
+            graphql_name,
             description,
             fields,
+            query_trails_module_name,
+            visibility,
         } = self;
 
         let mut graphql_attrs = GraphqlAttr::new();
+        graphql_attrs.push_key_value(format_ident!("name"), graphql_name);
         if let Some(description) = description {
             graphql_attrs.push_key_value(format_ident!("description"), description);
         }
@@ -2296,13 +3224,13 @@ impl<'doc> ToTokens for InputObject<'doc> {
         let temp_field_setters = fields
             .iter()
             .map(|field| {
-                let name = LitStr::new(&field.name.to_string(), Span::call_site());
+                let name = field.graphql_name;
                 let temp_name = format_ident!("{}_temp", field.name);
                 let rust_type = &field.ty;
                 quote! {
                     #name => {
-                        #temp_name = Some(
-                            query_trails::FromLookAheadValue::<#rust_type>::from(
+                        #temp_name = std::option::Option::Some(
+                            self::#query_trails_module_name::FromLookAheadValue::<#rust_type>::from(
                                 look_ahead_value
                             )
                         );
@@ -2316,48 +3244,196 @@ impl<'doc> ToTokens for InputObject<'doc> {
             .map(|field| {
                 let name = &field.name;
                 let temp_name = format_ident!("{}_temp", &field.name);
-                quote! {
-                    #name: #temp_name.unwrap_or_else(|| panic!("Field `{}` was not set", stringify!(#name))),
+                match field.missing_value() {
+                    Some(missing_value) => quote! {
+                        #name: #temp_name.unwrap_or_else(|| #missing_value),
+                    },
+                    None => quote! {
+                        #name: #temp_name.unwrap_or_else(|| std::panic!("Field `{}` was not set", std::stringify!(#name))),
+                    },
                 }
             })
             .collect::<Vec<_>>();
 
-        tokens.extend(quote! {
-            #[derive(juniper_from_schema::juniper::GraphQLInputObject, Clone, Debug)]
-            #graphql_attrs
-            pub struct #name {
-                #(#fields),*
+        let has_default_values = fields.iter().any(|field| field.default_value.is_some());
+
+        // Like enums, input objects with `rust_type` are converted through a hidden struct. So are
+        // input objects with default values, since juniper would also use those for `null`.
+        let glue = if self.rust_type.is_some() || has_default_values {
+            Some(format_ident!("__{}", name))
+        } else {
+            None
+        };
+        let rust_type = match rust_type {
+            Some(rust_type) => quote! { #rust_type },
+            None => quote! { #name },
+        };
+
+        if let Some(glue) = &glue {
+            let field_names = fields.iter().map(|field| &field.name).collect::<Vec<_>>();
+            let graphql_field_names = fields.iter().map(|field| field.graphql_name);
+            let glue_fields = fields.iter().map(|field| field.to_tokens_for_glue());
+            let from_glue = fields.iter().map(|field| field.value_from_glue());
+
+            let mut doc = RustDoc::new();
+            if self.rust_type.is_some() {
+                doc.push(format!(
+                    "Glue for mapping the GraphQL input object `{}` onto an existing type.",
+                    graphql_name
+                ));
+            } else {
+                doc.push(format!(
+                    "Glue for applying the default values of the GraphQL input object `{}`.",
+                    graphql_name
+                ));
+            }
+            doc.push("Generated by `juniper-from-schema`.");
+
+            let to_input_value = quote! {
+                juniper_from_schema::juniper::InputValue::object(
+                    std::iter::Iterator::collect(std::iter::IntoIterator::into_iter(std::vec![
+                        #(
+                            (
+                                #graphql_field_names,
+                                juniper_from_schema::juniper::ToInputValue::to_input_value(&self.#field_names),
+                            ),
+                        )*
+                    ]))
+                )
+            };
+            let input_field_defaults = fields
+                .iter()
+                .filter_map(|field| {
+                    let default_value = field.default_value.clone()?;
+                    Some((field.graphql_name, default_value))
+                })
+                .collect::<Vec<_>>();
+            let juniper_impls = mapped_type_juniper_impls(
+                &rust_type,
+                glue,
+                to_input_value,
+                false,
+                &input_field_defaults,
+            );
+
+            tokens.extend(quote! {
+                #[derive(
+                    juniper_from_schema::juniper::GraphQLInputObject,
+                    std::clone::Clone,
+                    std::fmt::Debug,
+                )]
+                #doc
+                #[doc(hidden)]
+                #graphql_attrs
+                #visibility struct #glue {
+                    #(#glue_fields),*
+                }
+
+                impl std::convert::From<#glue> for #rust_type {
+                    fn from(value: #glue) -> #rust_type {
+                        #rust_type {
+                            #(#field_names: #from_glue,)*
+                        }
+                    }
+                }
+
+                #juniper_impls
+            });
+        }
+
+        if self.rust_type.is_none() {
+            // Input objects without required fields implement `Default`, the others get a builder
+            let all_fields_optional = fields.iter().all(|field| field.missing_value().is_some());
+            let implements_default =
+                all_fields_optional && !derives.iter().any(|path| path_ends_with(path, "Default"));
+
+            let default_derive = if implements_default && !has_default_values {
+                quote! { std::default::Default, }
+            } else {
+                quote! {}
+            };
+
+            // Deriving `Default` would ignore the default values from the schema
+            if implements_default && has_default_values {
+                let field_names = fields.iter().map(|field| &field.name);
+                let missing_values = fields.iter().map(|field| field.missing_value());
+                tokens.extend(quote! {
+                    impl std::default::Default for #name {
+                        fn default() -> Self {
+                            #name {
+                                #(#field_names: #missing_values,)*
+                            }
+                        }
+                    }
+                });
+            }
+
+            if !all_fields_optional {
+                tokens.extend(input_object_builder(name, fields, visibility));
             }
 
-            impl<'a, 'b> query_trails::FromLookAheadValue<#name>
+            // The glue struct is the one juniper knows about, if there is one
+            let (graphql_derive, graphql_attrs) = if glue.is_some() {
+                (quote! {}, None)
+            } else {
+                (
+                    quote! { juniper_from_schema::juniper::GraphQLInputObject, },
+                    Some(graphql_attrs),
+                )
+            };
+            let struct_fields = fields
+                .iter()
+                .map(|field| field.to_tokens_for_struct(glue.is_none()));
+
+            let mut doc = RustDoc::new();
+            doc.push_description(*description);
+
+            tokens.extend(quote! {
+                #[derive(
+                    #graphql_derive
+                    std::clone::Clone,
+                    std::fmt::Debug,
+                    #default_derive
+                    #(#derives,)*
+                )]
+                #doc
+                #graphql_attrs
+                #visibility struct #name {
+                    #(#struct_fields),*
+                }
+            });
+        }
+
+        tokens.extend(quote! {
+            impl<'a, 'b> self::#query_trails_module_name::FromLookAheadValue<#rust_type>
                 for &'a juniper_from_schema::juniper::LookAheadValue<'b, juniper_from_schema::juniper::DefaultScalarValue>
             {
-                fn from(self) -> #name {
+                fn from(self) -> #rust_type {
                     match self {
                         juniper_from_schema::juniper::LookAheadValue::Object(pairs) => {
                             #(
-                                let mut #field_names = None;
+                                let mut #field_names = std::option::Option::None;
                             )*
                             for (look_ahead_key, look_ahead_value) in pairs {
                                 match *look_ahead_key {
                                     #(#temp_field_setters)*
-                                    other => panic!("Invalid input object key: {}", other),
+                                    other => std::panic!("Invalid input object key: {}", other),
                                 }
                             }
-                            #name {
+                            #rust_type {
                                 #(#field_setters)*
                             }
                         },
-                        juniper_from_schema::juniper::LookAheadValue::Enum(_) => panic!(
+                        juniper_from_schema::juniper::LookAheadValue::Enum(_) => std::panic!(
                             "Failed converting look ahead value. Expected object type got `enum`",
                         ),
-                        juniper_from_schema::juniper::LookAheadValue::Null => panic!(
+                        juniper_from_schema::juniper::LookAheadValue::Null => std::panic!(
                             "Failed converting look ahead value. Expected object type got `null`",
                         ),
-                        juniper_from_schema::juniper::LookAheadValue::List(_) => panic!(
+                        juniper_from_schema::juniper::LookAheadValue::List(_) => std::panic!(
                             "Failed converting look ahead value. Expected object type got `list`",
                         ),
-                        juniper_from_schema::juniper::LookAheadValue::Scalar(_) => panic!(
+                        juniper_from_schema::juniper::LookAheadValue::Scalar(_) => std::panic!(
                             "Failed converting look ahead value. Expected object type got `scalar`",
                         ),
                     }
@@ -2370,32 +3446,110 @@ impl<'doc> ToTokens for InputObject<'doc> {
 #[derive(Debug)]
 struct InputObjectField<'doc> {
     name: Ident,
+    graphql_name: &'doc str,
     ty: Type,
+    /// The value used when the field is left out, as a value of `ty`.
+    default_value: Option<TokenStream>,
+    default_value_graphql: Option<String>,
     description: Option<&'doc String>,
+    deprecation: Deprecation,
+    serde: bool,
 }
 
-impl<'doc> ToTokens for InputObjectField<'doc> {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
+impl<'doc> InputObjectField<'doc> {
+    /// The value of the field if it is left out, if it can be left out.
+    fn missing_value(&self) -> Option<TokenStream> {
+        self.default_value
+            .clone()
+            .or_else(|| input_field_missing_value(&self.ty))
+    }
+
+    /// The type of the field in the glue struct. Fields with default values are `Nullable<T>` there
+    /// so leaving them out can be told apart from setting them to `null`.
+    fn glue_ty(&self) -> Type {
+        match (&self.default_value, &self.ty) {
+            (Some(_), Type::Nullable(inner)) => Type::Tristate(inner.clone()),
+            _ => self.ty.clone(),
+        }
+    }
+
+    /// Convert the field from the glue struct `value`.
+    fn value_from_glue(&self) -> TokenStream {
+        let name = &self.name;
+        match &self.default_value {
+            Some(default_value) => quote! {
+                match value.#name {
+                    juniper_from_schema::juniper::Nullable::ImplicitNull => #default_value,
+                    juniper_from_schema::juniper::Nullable::ExplicitNull => std::option::Option::None,
+                    juniper_from_schema::juniper::Nullable::Some(value) => std::option::Option::Some(value),
+                }
+            },
+            None => quote! { value.#name },
+        }
+    }
+
+    fn to_tokens_for_glue(&self) -> TokenStream {
+        let name = &self.name;
+        let ty = self.glue_ty();
+        let graphql_attrs = self.graphql_attrs();
+        quote! {
+            #graphql_attrs
+            pub #name: #ty
+        }
+    }
+
+    fn graphql_attrs(&self) -> GraphqlAttr {
+        let mut graphql_attrs = GraphqlAttr::new();
+        graphql_attrs.push_key_value(format_ident!("name"), self.graphql_name);
+        if let Some(description) = self.description {
+            graphql_attrs.push_key_value(format_ident!("description"), description);
+        }
+        graphql_attrs
+    }
+
+    /// The field of the generated struct. `graphql` is whether the struct derives
+    /// `GraphQLInputObject` itself rather than going through a glue struct.
+    fn to_tokens_for_struct(&self, graphql: bool) -> TokenStream {
         let InputObjectField {
             name,
+            graphql_name,
             ty,
+            default_value: _,
+            default_value_graphql,
             description,
+            deprecation,
+            serde,
         } = self;
 
-        let mut graphql_attrs = GraphqlAttr::new();
-        if let Some(description) = description {
-            graphql_attrs.push_key_value(format_ident!("description"), description);
+        let graphql_attrs = if graphql {
+            Some(self.graphql_attrs())
+        } else {
+            None
+        };
+
+        // juniper doesn't support deprecating input object fields so it only goes in the docs
+        let mut doc = RustDoc::new();
+        if description.is_some() {
+            doc.push_description(*description);
+            doc.push_deprecation(Some(deprecation));
         }
+        doc.push_default_value(default_value_graphql.as_ref());
 
-        tokens.extend(quote! {
+        let serde_attr = serde_rename(*serde, graphql_name);
+
+        quote! {
+            #doc
             #graphql_attrs
+            #serde_attr
             pub #name: #ty
-        })
+        }
     }
 }
 
 #[derive(Debug)]
 struct SchemaType {
+    name: Ident,
+    visibility: syn::Visibility,
     query_type: syn::Type,
     mutation_type: syn::Type,
     subscription_type: syn::Type,
@@ -2404,6 +3558,8 @@ struct SchemaType {
 impl ToTokens for SchemaType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let SchemaType {
+            name,
+            visibility,
             query_type,
             mutation_type,
             subscription_type,
@@ -2411,7 +3567,7 @@ impl ToTokens for SchemaType {
 
         tokens.extend(quote! {
             /// The GraphQL schema type generated by `juniper-from-schema`.
-            pub type Schema = juniper_from_schema::juniper::RootNode<
+            #visibility type #name = juniper_from_schema::juniper::RootNode<
                 'static,
                 #query_type,
                 #mutation_type,
@@ -2421,6 +3577,318 @@ impl ToTokens for SchemaType {
     }
 }
 
+/// The name of an object, interface, or union, along with the lifetime to use it with if its Rust
+/// type has one.
+#[derive(Debug, Clone)]
+struct TypeName {
+    ident: Ident,
+    path: Option<syn::Path>,
+    lifetime: Option<syn::Lifetime>,
+}
+
+impl TypeName {
+    fn with_lifetime(self, lifetime: syn::Lifetime) -> Self {
+        Self {
+            lifetime: Some(lifetime),
+            ..self
+        }
+    }
+
+    /// The type of a query trail is only a marker, so types with lifetimes are used with
+    /// `'static` rather than tying the trail to the data being resolved.
+    fn for_query_trail(&self) -> Self {
+        match &self.lifetime {
+            Some(_) => self.clone().with_lifetime(parse_quote! { 'static }),
+            None => self.clone(),
+        }
+    }
+}
+
+impl ToTokens for TypeName {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let TypeName {
+            ident,
+            path,
+            lifetime,
+        } = self;
+        let ty = match path {
+            Some(path) => quote! { #path },
+            None => quote! { #ident },
+        };
+        match lifetime {
+            Some(lifetime) => tokens.extend(quote! { #ty<#lifetime> }),
+            None => tokens.extend(ty),
+        }
+    }
+}
+
+/// A builder for an input object that takes the required fields up front, so leaving one out is a
+/// compile error, and has a setter for each nullable field.
+fn input_object_builder(
+    name: &Ident,
+    fields: &[InputObjectField<'_>],
+    visibility: &syn::Visibility,
+) -> TokenStream {
+    let builder_name = format_ident!("{}Builder", name);
+
+    let (required, optional): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .partition(|field| field.missing_value().is_none());
+
+    let required_names = required.iter().map(|field| &field.name).collect::<Vec<_>>();
+    let required_types = required.iter().map(|field| &field.ty);
+    let optional_names = optional.iter().map(|field| &field.name).collect::<Vec<_>>();
+    let optional_defaults = optional.iter().map(|field| field.missing_value());
+
+    // Tristate fields take the whole `Nullable<T>` so an explicit `null` can be set as well
+    let optional_types = optional.iter().map(|field| match &field.ty {
+        Type::Nullable(inner) => &**inner,
+        ty => ty,
+    });
+    let optional_values = optional.iter().map(|field| {
+        let name = &field.name;
+        match &field.ty {
+            Type::Nullable(_) => quote! { std::option::Option::Some(#name) },
+            _ => quote! { #name },
+        }
+    });
+    let all_names = fields.iter().map(|field| &field.name).collect::<Vec<_>>();
+    let all_types = fields.iter().map(|field| &field.ty);
+
+    let builder_doc = format!(
+        " Builder for [`{}`], created with [`{}::builder`].",
+        name, name
+    );
+    let constructor_doc = format!(" Start building a `{}` from its required fields.", name);
+    let setter_docs =
+        optional
+            .iter()
+            .map(|field| match (&field.default_value_graphql, &field.ty) {
+                (Some(default_value), _) => format!(
+                    " Set `{}`. It is `{}` unless set.",
+                    field.graphql_name, default_value
+                ),
+                (None, Type::Tristate(_)) => format!(
+                    " Set `{}`. It is `Nullable::ImplicitNull` unless set.",
+                    field.graphql_name
+                ),
+                (None, _) => format!(" Set `{}`. It is `None` unless set.", field.graphql_name),
+            });
+    let build_doc = format!(" Build the `{}`.", name);
+
+    quote! {
+        #[doc = #builder_doc]
+        #[derive(std::clone::Clone, std::fmt::Debug)]
+        #visibility struct #builder_name {
+            #(#all_names: #all_types,)*
+        }
+
+        impl #name {
+            #[doc = #constructor_doc]
+            pub fn builder(#(#required_names: #required_types),*) -> #builder_name {
+                #builder_name {
+                    #(#required_names,)*
+                    #(#optional_names: #optional_defaults,)*
+                }
+            }
+        }
+
+        impl #builder_name {
+            #(
+                #[doc = #setter_docs]
+                pub fn #optional_names(mut self, #optional_names: #optional_types) -> Self {
+                    self.#optional_names = #optional_values;
+                    self
+                }
+            )*
+
+            #[doc = #build_doc]
+            pub fn build(self) -> #name {
+                #name {
+                    #(#all_names: self.#all_names,)*
+                }
+            }
+        }
+    }
+}
+
+/// The value of an input field that was left out, if it can be left out.
+fn input_field_missing_value(ty: &Type) -> Option<TokenStream> {
+    match ty {
+        Type::Nullable(_) => Some(quote! { std::option::Option::None }),
+        Type::Tristate(_) => Some(quote! { juniper_from_schema::juniper::Nullable::ImplicitNull }),
+        _ => None,
+    }
+}
+
+fn path_ends_with(path: &syn::Path, name: &str) -> bool {
+    matches!(path.segments.last(), Some(segment) if segment.ident == name)
+}
+
+fn serde_rename(serde: bool, graphql_name: &str) -> TokenStream {
+    if serde {
+        quote! { #[serde(rename = #graphql_name)] }
+    } else {
+        quote! {}
+    }
+}
+
+/// Implement juniper's traits for a type given with `@juniper(rust_type: "...")` by converting it
+/// to and from `glue`, which derives them. Output types, which are only enums, are resolved by
+/// converting to `glue` as well.
+///
+/// `input_field_defaults` are the GraphQL names and default values of input object fields. The
+/// glue struct leaves those out so juniper doesn't use them for `null`, so they're added to the
+/// meta type here for introspection to see them.
+fn mapped_type_juniper_impls(
+    rust_type: &TokenStream,
+    glue: &Ident,
+    to_input_value: TokenStream,
+    is_output_type: bool,
+    input_field_defaults: &[(&str, TokenStream)],
+) -> TokenStream {
+    let glue_meta = quote! {
+        <#glue as juniper_from_schema::juniper::GraphQLType<juniper_from_schema::juniper::DefaultScalarValue>>::meta(info, registry)
+    };
+    let meta = if input_field_defaults.is_empty() {
+        glue_meta
+    } else {
+        let graphql_names = input_field_defaults.iter().map(|(name, _)| name);
+        let default_values = input_field_defaults.iter().map(|(_, value)| value);
+        quote! {
+            let mut meta = #glue_meta;
+            if let juniper_from_schema::juniper::meta::MetaType::InputObject(input_object) = &mut meta {
+                for field in &mut input_object.input_fields {
+                    #(
+                        if field.name == #graphql_names {
+                            field.default_value = std::option::Option::Some(
+                                juniper_from_schema::juniper::ToInputValue::to_input_value(&#default_values),
+                            );
+                        }
+                    )*
+                }
+            }
+            meta
+        }
+    };
+
+    let output_impls = if is_output_type {
+        quote! {
+            impl juniper_from_schema::juniper::GraphQLValue<juniper_from_schema::juniper::DefaultScalarValue> for #rust_type {
+                type Context = ();
+                type TypeInfo = ();
+
+                fn type_name<'i>(&self, info: &'i ()) -> std::option::Option<&'i str> {
+                    <Self as juniper_from_schema::juniper::GraphQLType<juniper_from_schema::juniper::DefaultScalarValue>>::name(info)
+                }
+
+                fn resolve(
+                    &self,
+                    info: &(),
+                    selection_set: std::option::Option<&[juniper_from_schema::juniper::Selection<juniper_from_schema::juniper::DefaultScalarValue>]>,
+                    executor: &juniper_from_schema::juniper::Executor<(), juniper_from_schema::juniper::DefaultScalarValue>,
+                ) -> juniper_from_schema::juniper::ExecutionResult<juniper_from_schema::juniper::DefaultScalarValue> {
+                    juniper_from_schema::juniper::GraphQLValue::resolve(
+                        &#glue::from(self),
+                        info,
+                        selection_set,
+                        executor,
+                    )
+                }
+            }
+
+            impl juniper_from_schema::juniper::GraphQLValueAsync<juniper_from_schema::juniper::DefaultScalarValue> for #rust_type {
+                fn resolve_async<'a>(
+                    &'a self,
+                    info: &'a (),
+                    selection_set: std::option::Option<&'a [juniper_from_schema::juniper::Selection<juniper_from_schema::juniper::DefaultScalarValue>]>,
+                    executor: &'a juniper_from_schema::juniper::Executor<(), juniper_from_schema::juniper::DefaultScalarValue>,
+                ) -> juniper_from_schema::futures::future::BoxFuture<'a, juniper_from_schema::juniper::ExecutionResult<juniper_from_schema::juniper::DefaultScalarValue>> {
+                    let value = juniper_from_schema::juniper::GraphQLValue::resolve(self, info, selection_set, executor);
+                    std::boxed::Box::pin(juniper_from_schema::futures::future::ready(value))
+                }
+            }
+
+            impl juniper_from_schema::juniper::marker::IsOutputType<juniper_from_schema::juniper::DefaultScalarValue> for #rust_type {}
+        }
+    } else {
+        quote! {
+            impl juniper_from_schema::juniper::GraphQLValue<juniper_from_schema::juniper::DefaultScalarValue> for #rust_type {
+                type Context = ();
+                type TypeInfo = ();
+
+                fn type_name<'i>(&self, info: &'i ()) -> std::option::Option<&'i str> {
+                    <Self as juniper_from_schema::juniper::GraphQLType<juniper_from_schema::juniper::DefaultScalarValue>>::name(info)
+                }
+            }
+
+            impl juniper_from_schema::juniper::GraphQLValueAsync<juniper_from_schema::juniper::DefaultScalarValue> for #rust_type {}
+        }
+    };
+
+    quote! {
+        impl juniper_from_schema::juniper::GraphQLType<juniper_from_schema::juniper::DefaultScalarValue> for #rust_type {
+            fn name(info: &()) -> std::option::Option<&str> {
+                <#glue as juniper_from_schema::juniper::GraphQLType<juniper_from_schema::juniper::DefaultScalarValue>>::name(info)
+            }
+
+            fn meta<'r>(
+                info: &(),
+                registry: &mut juniper_from_schema::juniper::Registry<'r, juniper_from_schema::juniper::DefaultScalarValue>,
+            ) -> juniper_from_schema::juniper::meta::MetaType<'r, juniper_from_schema::juniper::DefaultScalarValue>
+            where
+                juniper_from_schema::juniper::DefaultScalarValue: 'r,
+            {
+                #meta
+            }
+        }
+
+        #output_impls
+
+        impl juniper_from_schema::juniper::FromInputValue<juniper_from_schema::juniper::DefaultScalarValue> for #rust_type {
+            fn from_input_value(
+                value: &juniper_from_schema::juniper::InputValue<juniper_from_schema::juniper::DefaultScalarValue>,
+            ) -> std::option::Option<Self> {
+                <#glue as juniper_from_schema::juniper::FromInputValue<juniper_from_schema::juniper::DefaultScalarValue>>::from_input_value(value)
+                    .map(std::convert::From::from)
+            }
+        }
+
+        impl juniper_from_schema::juniper::ToInputValue<juniper_from_schema::juniper::DefaultScalarValue> for #rust_type {
+            fn to_input_value(&self) -> juniper_from_schema::juniper::InputValue<juniper_from_schema::juniper::DefaultScalarValue> {
+                #to_input_value
+            }
+        }
+
+        impl juniper_from_schema::juniper::marker::IsInputType<juniper_from_schema::juniper::DefaultScalarValue> for #rust_type {}
+    }
+}
+
+/// The lifetime parameter of generated items for types with `@juniper(lifetime: true)`. It can't
+/// be `'a` since that is used by the field methods.
+fn definition_lifetime() -> syn::Lifetime {
+    parse_quote! { 'data }
+}
+
+fn lifetime_generics(has_lifetime: bool) -> Option<TokenStream> {
+    if has_lifetime {
+        let lifetime = definition_lifetime();
+        Some(quote! { <#lifetime> })
+    } else {
+        None
+    }
+}
+
+/// Name of the struct holding the arguments of `field_name` on `type_name`, such as
+/// `QueryAllPostsArgs`.
+fn args_struct_name(type_name: &Ident, field_name: &Ident) -> Ident {
+    format_ident!(
+        "{}{}Args",
+        type_name.unraw(),
+        field_name.unraw().to_string().to_camel_case()
+    )
+}
+
 fn add_deprecation_graphql_attr_token(
     directives: &FieldDirectives,
     graphql_attrs: &mut GraphqlAttr,
@@ -2543,6 +4011,102 @@ impl ToTokens for GraphqlAttrItem {
     }
 }
 
+/// Rustdoc attributes generated from descriptions, deprecations, and default values in the
+/// schema.
+///
+/// juniper falls back to using doc comments as GraphQL descriptions. So items juniper reads docs
+/// from should only be given a `RustDoc` when they also get an explicit `description`.
+#[derive(Debug, Default)]
+struct RustDoc {
+    paragraphs: Vec<String>,
+}
+
+impl RustDoc {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(&mut self, paragraph: impl Into<String>) {
+        self.paragraphs.push(paragraph.into());
+    }
+
+    fn push_description(&mut self, description: Option<&String>) {
+        if let Some(description) = description {
+            self.push(description.trim());
+        }
+    }
+
+    fn push_deprecation(&mut self, deprecation: Option<&Deprecation>) {
+        match deprecation {
+            Some(Deprecation::Deprecated(Some(reason))) => {
+                self.push(format!("**Deprecated**: {}", reason))
+            }
+            Some(Deprecation::Deprecated(None)) => self.push("**Deprecated**"),
+            Some(Deprecation::NoDeprecation) | None => {}
+        }
+    }
+
+    fn push_default_value(&mut self, default_value: Option<&String>) {
+        if let Some(default_value) = default_value {
+            self.push(format!("Defaults to `{}`.", default_value));
+        }
+    }
+
+    fn push_arguments(&mut self, args: &[FieldArg<'_>]) {
+        let items = args
+            .iter()
+            .filter(|arg| {
+                arg.description.is_some()
+                    || arg.default_value_graphql.is_some()
+                    || arg.deprecation.is_deprecated()
+            })
+            .map(|arg| {
+                let mut parts = vec![];
+                match (arg.description, &arg.default_value_graphql) {
+                    (Some(description), Some(default_value)) => parts.push(format!(
+                        "{} (defaults to `{}`)",
+                        description.trim(),
+                        default_value
+                    )),
+                    (Some(description), None) => parts.push(description.trim().to_string()),
+                    (None, Some(default_value)) => {
+                        parts.push(format!("Defaults to `{}`.", default_value))
+                    }
+                    (None, None) => {}
+                }
+                match &arg.deprecation {
+                    Deprecation::Deprecated(Some(reason)) => {
+                        parts.push(format!("**Deprecated**: {}", reason))
+                    }
+                    Deprecation::Deprecated(None) => parts.push("**Deprecated**".to_string()),
+                    Deprecation::NoDeprecation => {}
+                }
+                format!("- `{}`: {}", arg.name_without_raw_ident, parts.join(" "))
+            })
+            .collect::<Vec<_>>();
+
+        if !items.is_empty() {
+            self.push("# Arguments");
+            self.push(items.join("\n"));
+        }
+    }
+}
+
+impl ToTokens for RustDoc {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for (idx, paragraph) in self.paragraphs.iter().enumerate() {
+            if idx > 0 {
+                tokens.extend(quote! { #[doc = ""] });
+            }
+
+            for line in paragraph.lines() {
+                let line = format!(" {}", line);
+                tokens.extend(quote! { #[doc = #line] });
+            }
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 enum FieldLocation {
     Object,
@@ -2563,3 +4127,26 @@ fn maybe_wrap_final_return_type_in_result(
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rust_doc_separates_paragraphs() {
+        let description = "First line\nSecond line".to_string();
+
+        let mut doc = RustDoc::new();
+        doc.push_description(Some(&description));
+        doc.push_deprecation(Some(&Deprecation::Deprecated(Some("Gone".to_string()))));
+
+        let expected = quote! {
+            #[doc = " First line"]
+            #[doc = " Second line"]
+            #[doc = ""]
+            #[doc = " **Deprecated**: Gone"]
+        };
+
+        assert_eq!(expected.to_string(), doc.to_token_stream().to_string());
+    }
+}
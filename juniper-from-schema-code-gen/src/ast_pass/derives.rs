@@ -0,0 +1,36 @@
+use syn::{parse::Parser, punctuated::Punctuated, Token};
+
+/// Extra derives for generated enums, input objects, and scalars.
+///
+/// These are on top of the derives that are always generated. `@juniper(derive: "...")` on a type
+/// replaces the list for that type.
+#[derive(Debug, Clone, Default)]
+pub struct Derives {
+    pub enums: Vec<syn::Path>,
+    pub input_objects: Vec<syn::Path>,
+    pub scalars: Vec<syn::Path>,
+}
+
+/// Parse a comma separated list of derives such as `"serde::Serialize, serde::Deserialize"`.
+pub fn parse_derives(value: &str) -> Result<Vec<syn::Path>, String> {
+    Punctuated::<syn::Path, Token![,]>::parse_terminated
+        .parse_str(value)
+        .map(|paths| paths.into_iter().collect())
+        .map_err(|err| {
+            format!(
+                "Failed to parse `{}` as a comma separated list of derives: {}",
+                value, err
+            )
+        })
+}
+
+/// Whether `serde`'s `#[serde(...)]` attributes are available, which is the case if one of the
+/// derives is `Serialize` or `Deserialize`.
+pub fn derives_serde(derives: &[syn::Path]) -> bool {
+    derives.iter().any(|path| {
+        matches!(
+            path.segments.last(),
+            Some(segment) if segment.ident == "Serialize" || segment.ident == "Deserialize"
+        )
+    })
+}
@@ -1,10 +1,12 @@
 use crate::ast_pass::{
     code_gen_pass::CodeGenPass,
+    derives::parse_derives,
     error::{self, ErrorKind, Juniper, UnsupportedDirectiveKind, ValueType},
-    EmitError,
+    is_special_case_scalar, AstData, EmitError,
 };
 use graphql_parser::{query::Value, schema::*};
 use std::convert::identity;
+use syn::{ext::IdentExt, parse::Parser, Ident};
 
 pub trait FromDirective: Sized {
     fn from_directive<'doc>(dir: &'doc Directive<'doc, &'doc str>) -> Result<Self, ErrorKind>;
@@ -41,6 +43,15 @@ pub enum Deprecation {
     Deprecated(Option<String>),
 }
 
+impl Deprecation {
+    pub fn is_deprecated(&self) -> bool {
+        match self {
+            Self::NoDeprecation => false,
+            Self::Deprecated(_) => true,
+        }
+    }
+}
+
 impl Default for Deprecation {
     fn default() -> Self {
         Self::NoDeprecation
@@ -94,6 +105,35 @@ impl FromDirective for Deprecation {
     }
 }
 
+#[derive(Debug)]
+pub struct SpecifiedBy {
+    pub url: String,
+}
+
+impl FromDirective for SpecifiedBy {
+    fn from_directive<'doc>(dir: &'doc Directive<'doc, &'doc str>) -> Result<Self, ErrorKind> {
+        if dir.arguments.len() != 1 {
+            return Err(ErrorKind::UnsupportedDirective(
+                UnsupportedDirectiveKind::SpecifiedBy(error::SpecifiedBy::WrongNumberOfArgs(
+                    dir.arguments.len(),
+                )),
+            ));
+        }
+
+        let (key, value) = &dir.arguments[0];
+        if *key != "url" {
+            return Err(ErrorKind::UnsupportedDirective(
+                UnsupportedDirectiveKind::SpecifiedBy(error::SpecifiedBy::InvalidKey(
+                    key.to_string(),
+                )),
+            ));
+        }
+
+        let url = value_as_string(value)?.to_string();
+        Ok(SpecifiedBy { url })
+    }
+}
+
 #[derive(Debug)]
 pub struct JuniperDirective<T> {
     pub name: String,
@@ -169,6 +209,10 @@ impl_from_directive_for! { (T1, T2) }
 impl_from_directive_for! { (T1, T2, T3) }
 impl_from_directive_for! { (T1, T2, T3, T4) }
 impl_from_directive_for! { (T1, T2, T3, T4, T5) }
+impl_from_directive_for! { (T1, T2, T3, T4, T5, T6) }
+impl_from_directive_for! { (T1, T2, T3, T4, T5, T6, T7) }
+impl_from_directive_for! { (T1, T2, T3, T4, T5, T6, T7, T8) }
+impl_from_directive_for! { (T1, T2, T3, T4, T5, T6, T7, T8, T9) }
 
 #[derive(Debug)]
 pub struct FieldDirectives {
@@ -178,19 +222,68 @@ pub struct FieldDirectives {
     pub r#async: Async,
     pub stream_type: Option<StreamType>,
     pub stream_item_infallible: Option<StreamItemInfallible>,
+    pub rust_name: Option<RustName>,
+    pub error_type: Option<ErrorType>,
+    pub args_struct: ArgsStruct,
+    pub executor: ExecutorParam,
 }
 
-#[derive(Debug, Eq, PartialEq)]
+/// `@juniper` settings given on a type or on the schema, which apply to all fields that don't set
+/// them.
+#[derive(Debug, Default, Clone)]
+pub struct FieldDefaults {
+    pub ownership: Option<Ownership>,
+    pub infallible: Option<Infallible>,
+    pub r#async: Option<Async>,
+    pub error_type: Option<ErrorType>,
+    pub args_struct: Option<ArgsStruct>,
+    pub executor: Option<ExecutorParam>,
+}
+
+impl FieldDefaults {
+    /// Use the settings from `self` and fall back to `other` for the ones that aren't set.
+    pub fn or(self, other: FieldDefaults) -> Self {
+        Self {
+            ownership: self.ownership.or(other.ownership),
+            infallible: self.infallible.or(other.infallible),
+            r#async: self.r#async.or(other.r#async),
+            error_type: self.error_type.or(other.error_type),
+            args_struct: self.args_struct.or(other.args_struct),
+            executor: self.executor.or(other.executor),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum Ownership {
     Owned,
     Borrowed,
     AsRef,
+    Arc,
+    Str,
+    Cow,
+    Slice,
+    Iter,
 }
 
 impl Ownership {
     pub fn is_as_ref(&self) -> bool {
         matches!(self, Ownership::AsRef)
     }
+
+    /// The value used in the schema.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Ownership::Owned => "owned",
+            Ownership::Borrowed => "borrowed",
+            Ownership::AsRef => "as_ref",
+            Ownership::Arc => "arc",
+            Ownership::Str => "str",
+            Ownership::Cow => "cow",
+            Ownership::Slice => "slice",
+            Ownership::Iter => "iter",
+        }
+    }
 }
 
 impl Default for Ownership {
@@ -216,6 +309,11 @@ impl FromDirectiveArguments for Ownership {
                 "owned" => Ownership::Owned,
                 "borrowed" => Ownership::Borrowed,
                 "as_ref" => Ownership::AsRef,
+                "arc" => Ownership::Arc,
+                "str" => Ownership::Str,
+                "cow" => Ownership::Cow,
+                "slice" => Ownership::Slice,
+                "iter" => Ownership::Iter,
                 value => {
                     return Err(ErrorKind::UnsupportedDirective(
                         UnsupportedDirectiveKind::Ownership(error::Ownership::InvalidValue(
@@ -231,7 +329,7 @@ impl FromDirectiveArguments for Ownership {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Infallible {
     pub value: bool,
 }
@@ -261,7 +359,7 @@ impl FromDirectiveArguments for Infallible {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Async {
     pub value: bool,
 }
@@ -291,6 +389,86 @@ impl Default for Async {
     }
 }
 
+/// Whether the `field_*` trait method takes the executor.
+#[derive(Debug, Clone, Copy)]
+pub struct ExecutorParam {
+    pub value: bool,
+}
+
+impl Default for ExecutorParam {
+    fn default() -> Self {
+        ExecutorParam { value: true }
+    }
+}
+
+impl FromDirectiveArguments for ExecutorParam {
+    const KEY: &'static str = "executor";
+
+    fn from_directive_args<'doc>(
+        (key, value): &'doc (&'doc str, Value<'doc, &'doc str>),
+    ) -> Option<Result<Self, ErrorKind>> {
+        if *key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let value = value_as_bool(value)?;
+            Ok(Self { value })
+        })();
+
+        Some(directive)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ArgsStruct {
+    pub value: bool,
+}
+
+impl FromDirectiveArguments for ArgsStruct {
+    const KEY: &'static str = "args_struct";
+
+    fn from_directive_args<'doc>(
+        (key, value): &'doc (&'doc str, Value<'doc, &'doc str>),
+    ) -> Option<Result<Self, ErrorKind>> {
+        if *key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let value = value_as_bool(value)?;
+            Ok(Self { value })
+        })();
+
+        Some(directive)
+    }
+}
+
+/// Whether the Rust type of an object has a lifetime parameter, such as `User<'a>`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Lifetime {
+    pub value: bool,
+}
+
+impl FromDirectiveArguments for Lifetime {
+    const KEY: &'static str = "lifetime";
+
+    fn from_directive_args<'doc>(
+        (key, value): &'doc (&'doc str, Value<'doc, &'doc str>),
+    ) -> Option<Result<Self, ErrorKind>> {
+        if *key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let value = value_as_bool(value)?;
+            Ok(Self { value })
+        })();
+
+        Some(directive)
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct StreamType {
     pub value: String,
@@ -341,6 +519,250 @@ impl FromDirectiveArguments for StreamItemInfallible {
     }
 }
 
+#[derive(Debug, Default, Clone)]
+pub struct ErrorType {
+    pub value: String,
+}
+
+impl FromDirectiveArguments for ErrorType {
+    const KEY: &'static str = "error_type";
+
+    fn from_directive_args<'doc>(
+        (key, value): &'doc (&'doc str, Value<'doc, &'doc str>),
+    ) -> Option<Result<Self, ErrorKind>> {
+        if *key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let value = value_as_string(value)?;
+
+            if let Err(err) = syn::parse_str::<syn::Type>(value) {
+                return Err(ErrorKind::InvalidErrorType(err.to_string()));
+            }
+
+            Ok(Self {
+                value: value.to_string(),
+            })
+        })();
+
+        Some(directive)
+    }
+}
+
+/// The path of a Rust type defined elsewhere that an object type is bound to.
+#[derive(Debug, Default)]
+pub struct RustPath {
+    pub value: String,
+}
+
+impl FromDirectiveArguments for RustPath {
+    const KEY: &'static str = "rust_path";
+
+    fn from_directive_args<'doc>(
+        (key, value): &'doc (&'doc str, Value<'doc, &'doc str>),
+    ) -> Option<Result<Self, ErrorKind>> {
+        if *key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let value = value_as_string(value)?;
+
+            if let Err(err) = syn::parse_str::<syn::Path>(value) {
+                return Err(ErrorKind::InvalidRustPath(err.to_string()));
+            }
+
+            Ok(Self {
+                value: value.to_string(),
+            })
+        })();
+
+        Some(directive)
+    }
+}
+
+/// An existing Rust type that an enum or input object is mapped onto instead of generating one.
+#[derive(Debug, Default)]
+pub struct RustType {
+    pub value: String,
+}
+
+impl FromDirectiveArguments for RustType {
+    const KEY: &'static str = "rust_type";
+
+    fn from_directive_args<'doc>(
+        (key, value): &'doc (&'doc str, Value<'doc, &'doc str>),
+    ) -> Option<Result<Self, ErrorKind>> {
+        if *key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let value = value_as_string(value)?;
+
+            if let Err(err) = syn::parse_str::<syn::Path>(value) {
+                return Err(ErrorKind::InvalidRustPath(err.to_string()));
+            }
+
+            Ok(Self {
+                value: value.to_string(),
+            })
+        })();
+
+        Some(directive)
+    }
+}
+
+/// How a nullable argument or input field is represented in Rust.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NullableKind {
+    /// `Option<T>`, where an explicit `null` and a missing value are both `None`.
+    #[default]
+    Option,
+    /// juniper's `Nullable<T>`, which tells an explicit `null` apart from a missing value.
+    Tristate,
+}
+
+impl FromDirectiveArguments for NullableKind {
+    const KEY: &'static str = "nullable_kind";
+
+    fn from_directive_args<'doc>(
+        (key, value): &'doc (&'doc str, Value<'doc, &'doc str>),
+    ) -> Option<Result<Self, ErrorKind>> {
+        if *key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| match value_as_string(value)? {
+            "option" => Ok(NullableKind::Option),
+            "tristate" => Ok(NullableKind::Tristate),
+            other => Err(ErrorKind::InvalidNullableKind(other.to_string())),
+        })();
+
+        Some(directive)
+    }
+}
+
+/// Derives for a generated enum, input object, or scalar, replacing the configured defaults.
+#[derive(Debug, Default)]
+pub struct Derive {
+    pub value: Vec<syn::Path>,
+}
+
+impl FromDirectiveArguments for Derive {
+    const KEY: &'static str = "derive";
+
+    fn from_directive_args<'doc>(
+        (key, value): &'doc (&'doc str, Value<'doc, &'doc str>),
+    ) -> Option<Result<Self, ErrorKind>> {
+        if *key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let value = value_as_string(value)?;
+            let value = parse_derives(value).map_err(ErrorKind::InvalidDerive)?;
+            Ok(Self { value })
+        })();
+
+        Some(directive)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RustName {
+    pub value: String,
+}
+
+impl FromDirectiveArguments for RustName {
+    const KEY: &'static str = "rust_name";
+
+    fn from_directive_args<'doc>(
+        (key, value): &'doc (&'doc str, Value<'doc, &'doc str>),
+    ) -> Option<Result<Self, ErrorKind>> {
+        if *key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let value = value_as_string(value)?;
+
+            if !is_valid_rust_name(value) {
+                return Err(ErrorKind::UnsupportedDirective(
+                    UnsupportedDirectiveKind::RustName(error::RustName::InvalidValue(
+                        value.to_string(),
+                    )),
+                ));
+            }
+
+            Ok(Self {
+                value: value.to_string(),
+            })
+        })();
+
+        Some(directive)
+    }
+}
+
+/// Find the name given with `@juniper(rust_name: "...")`.
+///
+/// This doesn't report errors, which happens when the directives are parsed by `CodeGenPass`.
+/// It is used where names have to be known before that, for example for types used before
+/// they're defined. Invalid names are ignored so code generation can carry on until the errors
+/// are reported.
+pub fn rust_name<'doc>(directives: &'doc [Directive<'doc, &'doc str>]) -> Option<&'doc str> {
+    directives
+        .iter()
+        .filter(|directive| directive.name == "juniper")
+        .flat_map(|directive| &directive.arguments)
+        .find_map(|(key, value)| match value {
+            Value::String(value) if *key == RustName::KEY => Some(value.as_str()),
+            _ => None,
+        })
+        .filter(|value| is_valid_rust_name(value))
+}
+
+/// Look up `nullable_kind` without reporting errors, for when the argument's or input field's
+/// directives are parsed elsewhere.
+pub fn nullable_kind<'doc>(directives: &'doc [Directive<'doc, &'doc str>]) -> NullableKind {
+    directives
+        .iter()
+        .filter(|directive| directive.name == "juniper")
+        .flat_map(|directive| &directive.arguments)
+        .find_map(|arg| NullableKind::from_directive_args(arg)?.ok())
+        .unwrap_or_default()
+}
+
+/// Look up `args_struct` without reporting errors, for when the field's directives are parsed
+/// elsewhere.
+pub fn args_struct<'doc>(directives: &'doc [Directive<'doc, &'doc str>]) -> Option<bool> {
+    directives
+        .iter()
+        .filter(|directive| directive.name == "juniper")
+        .flat_map(|directive| &directive.arguments)
+        .find_map(|(key, value)| match value {
+            Value::Boolean(value) if *key == ArgsStruct::KEY => Some(*value),
+            _ => None,
+        })
+}
+
+/// Find the type given with `@juniper(rust_type: "...")` without reporting errors, like
+/// [`rust_name`].
+pub fn rust_type<'doc>(directives: &'doc [Directive<'doc, &'doc str>]) -> Option<syn::Path> {
+    directives
+        .iter()
+        .filter(|directive| directive.name == "juniper")
+        .flat_map(|directive| &directive.arguments)
+        .find_map(|arg| RustType::from_directive_args(arg)?.ok())
+        .and_then(|rust_type| syn::parse_str(&rust_type.value).ok())
+}
+
+fn is_valid_rust_name(value: &str) -> bool {
+    // Keywords are escaped when generating code, so raw identifiers aren't needed
+    !value.starts_with("r#") && Ident::parse_any.parse_str(value).is_ok()
+}
+
 #[derive(Debug)]
 pub struct DateTimeScalarArguments {
     pub with_time_zone: bool,
@@ -403,31 +825,58 @@ pub trait ParseDirective<T> {
     fn parse_directives(&mut self, input: T) -> Self::Output;
 }
 
-impl<'doc> ParseDirective<&'doc Field<'doc, &'doc str>> for CodeGenPass<'doc> {
+/// A field along with the name of the object or interface it's defined on, which might set
+/// defaults for the field's `@juniper` settings.
+#[derive(Debug)]
+pub struct FieldOfType<'a>(pub &'a str, pub &'a Field<'a, &'a str>);
+
+impl<'doc> ParseDirective<FieldOfType<'doc>> for CodeGenPass<'doc> {
     type Output = FieldDirectives;
 
-    fn parse_directives(&mut self, input: &'doc Field<'doc, &'doc str>) -> Self::Output {
-        let mut ownership = Ownership::default();
+    fn parse_directives(
+        &mut self,
+        FieldOfType(type_name, input): FieldOfType<'doc>,
+    ) -> Self::Output {
+        let mut ownership = None::<Ownership>;
         let mut deprecated = None::<Deprecation>;
-        let mut infallible = Infallible::default();
-        let mut r#async = Async::default();
+        let mut infallible = None::<Infallible>;
+        let mut r#async = None::<Async>;
         let mut stream_type = None::<StreamType>;
         let mut stream_item_infallible = None::<StreamItemInfallible>;
+        let mut rust_name = None::<RustName>;
+        let mut error_type = None::<ErrorType>;
+        let mut args_struct = None::<ArgsStruct>;
+        let mut executor = None::<ExecutorParam>;
 
         for dir in &input.directives {
-            if let Ok(juniper_directive) = JuniperDirective::<(
-                Ownership,
-                Infallible,
-                Async,
-                Option<StreamType>,
-                Option<StreamItemInfallible>,
-            )>::from_directive(dir)
-            {
-                ownership = juniper_directive.args.0;
-                infallible = juniper_directive.args.1;
-                r#async = juniper_directive.args.2;
-                stream_type = juniper_directive.args.3;
-                stream_item_infallible = juniper_directive.args.4;
+            if dir.name == "juniper" {
+                match JuniperDirective::<(
+                    Option<Ownership>,
+                    Option<Infallible>,
+                    Option<Async>,
+                    Option<StreamType>,
+                    Option<StreamItemInfallible>,
+                    Option<RustName>,
+                    Option<ErrorType>,
+                    Option<ArgsStruct>,
+                    Option<ExecutorParam>,
+                )>::from_directive(dir)
+                {
+                    Ok(juniper_directive) => {
+                        ownership = juniper_directive.args.0;
+                        infallible = juniper_directive.args.1;
+                        r#async = juniper_directive.args.2;
+                        stream_type = juniper_directive.args.3;
+                        stream_item_infallible = juniper_directive.args.4;
+                        rust_name = juniper_directive.args.5;
+                        error_type = juniper_directive.args.6;
+                        args_struct = juniper_directive.args.7;
+                        executor = juniper_directive.args.8;
+                    }
+                    Err(err) => {
+                        self.emit_error(dir.position, err);
+                    }
+                }
                 continue;
             }
 
@@ -436,6 +885,10 @@ impl<'doc> ParseDirective<&'doc Field<'doc, &'doc str>> for CodeGenPass<'doc> {
                 continue;
             }
 
+            if self.ast_data.is_custom_directive(dir.name) {
+                continue;
+            }
+
             self.emit_error(
                 dir.position,
                 ErrorKind::UnknownDirective {
@@ -444,51 +897,83 @@ impl<'doc> ParseDirective<&'doc Field<'doc, &'doc str>> for CodeGenPass<'doc> {
             );
         }
 
+        let defaults = self.ast_data.field_defaults(type_name);
+
         FieldDirectives {
-            ownership,
+            ownership: ownership.or(defaults.ownership).unwrap_or_default(),
             deprecated,
-            infallible,
-            r#async,
+            infallible: infallible.or(defaults.infallible).unwrap_or_default(),
+            r#async: r#async.or(defaults.r#async).unwrap_or_default(),
             stream_type,
             stream_item_infallible,
+            rust_name,
+            error_type: error_type.or(defaults.error_type),
+            args_struct: args_struct.or(defaults.args_struct).unwrap_or_default(),
+            executor: executor.or(defaults.executor).unwrap_or_default(),
         }
     }
 }
 
-impl<'doc> ParseDirective<&'doc EnumValue<'doc, &'doc str>> for CodeGenPass<'doc> {
-    type Output = Deprecation;
+macro_rules! supports_deprecation_and_juniper {
+    ($ty:ty, $juniper_args:ty) => {
+        impl<'doc> ParseDirective<&'doc $ty> for CodeGenPass<'doc> {
+            type Output = Deprecation;
 
-    fn parse_directives(&mut self, input: &'doc EnumValue<'doc, &'doc str>) -> Self::Output {
-        let mut deprecated = Deprecation::default();
+            fn parse_directives(&mut self, input: &'doc $ty) -> Self::Output {
+                let mut deprecated = Deprecation::default();
+
+                for dir in &input.directives {
+                    if self.ast_data.is_custom_directive(dir.name) {
+                        continue;
+                    }
+
+                    // The arguments are looked up with `rust_name` and `nullable_kind` since they're
+                    // also needed where the directives aren't parsed
+                    if dir.name == "juniper" {
+                        if let Err(err) = JuniperDirective::<$juniper_args>::from_directive(dir) {
+                            self.emit_error(dir.position, err);
+                        }
+                        continue;
+                    }
 
-        for dir in &input.directives {
-            match Deprecation::from_directive(dir) {
-                Ok(x) => {
-                    deprecated = x;
-                }
-                Err(err) => {
-                    self.emit_error(dir.position, err);
+                    match Deprecation::from_directive(dir) {
+                        Ok(x) => {
+                            deprecated = x;
+                        }
+                        Err(err) => {
+                            self.emit_error(dir.position, err);
+                        }
+                    }
                 }
+
+                deprecated
             }
         }
-
-        deprecated
-    }
+    };
 }
 
+supports_deprecation_and_juniper!(EnumValue<'doc, &'doc str>, Option<RustName>);
+supports_deprecation_and_juniper!(
+    InputValue<'doc, &'doc str>,
+    (Option<RustName>, Option<NullableKind>)
+);
+
 #[derive(Debug)]
 pub struct DateTimeScalarType<'a>(pub &'a ScalarType<'a, &'a str>);
 
-impl<'doc, T> ParseDirective<DateTimeScalarType<'doc>> for T
-where
-    T: EmitError,
-{
+impl<'doc> ParseDirective<DateTimeScalarType<'doc>> for AstData<'doc> {
     type Output = DateTimeScalarArguments;
 
     fn parse_directives(&mut self, input: DateTimeScalarType<'doc>) -> Self::Output {
         let mut args = DateTimeScalarArguments::default();
 
         for dir in &input.0.directives {
+            // `@specifiedBy` and custom directives are parsed together with the directives on
+            // other scalars
+            if dir.name == "specifiedBy" || self.is_custom_directive(dir.name) {
+                continue;
+            }
+
             match JuniperDirective::<DateTimeScalarArguments>::from_directive(dir) {
                 Ok(x) => {
                     args = x.args;
@@ -503,13 +988,187 @@ where
     }
 }
 
-macro_rules! supports_no_directives {
+/// `@juniper` settings on object types.
+#[derive(Debug, Default)]
+pub struct ObjectTypeDirectives {
+    pub field_defaults: FieldDefaults,
+    pub lifetime: Option<Lifetime>,
+    pub rust_path: Option<RustPath>,
+}
+
+/// `@juniper` settings on interfaces.
+#[derive(Debug, Default)]
+pub struct InterfaceTypeDirectives {
+    pub field_defaults: FieldDefaults,
+}
+
+/// Parse the field defaults and `rust_name` on a type, along with the settings only supported on
+/// that kind of type, which become fields of `$output` with the same name.
+macro_rules! supports_field_defaults_and_rust_name {
+    ($ty:ty => $output:ident { $($setting:ident: $setting_ty:ty),* $(,)? }) => {
+        impl<'doc> ParseDirective<&'doc $ty> for AstData<'doc> {
+            type Output = $output;
+
+            fn parse_directives(&mut self, input: &'doc $ty) -> Self::Output {
+                let mut directives = $output::default();
+
+                for dir in &input.directives {
+                    // Other directives are checked by `CodeGenPass`
+                    if dir.name != "juniper" {
+                        continue;
+                    }
+
+                    match JuniperDirective::<(
+                        Option<Ownership>,
+                        Option<Infallible>,
+                        Option<Async>,
+                        Option<ErrorType>,
+                        Option<ArgsStruct>,
+                        Option<ExecutorParam>,
+                        Option<RustName>,
+                        $(Option<$setting_ty>,)*
+                    )>::from_directive(dir)
+                    {
+                        Ok(JuniperDirective {
+                            args: (
+                                ownership,
+                                infallible,
+                                r#async,
+                                error_type,
+                                args_struct,
+                                executor,
+                                _rust_name,
+                                $($setting,)*
+                            ),
+                            ..
+                        }) => {
+                            directives = $output {
+                                field_defaults: FieldDefaults {
+                                    ownership,
+                                    infallible,
+                                    r#async,
+                                    error_type,
+                                    args_struct,
+                                    executor,
+                                },
+                                $($setting,)*
+                            };
+                        }
+                        Err(err) => {
+                            self.emit_error(dir.position, err);
+                        }
+                    }
+                }
+
+                directives
+            }
+        }
+    };
+}
+
+supports_field_defaults_and_rust_name!(ObjectType<'doc, &'doc str> => ObjectTypeDirectives {
+    lifetime: Lifetime,
+    rust_path: RustPath,
+});
+supports_field_defaults_and_rust_name!(InterfaceType<'doc, &'doc str> => InterfaceTypeDirectives {});
+
+impl<'doc> ParseDirective<&'doc SchemaDefinition<'doc, &'doc str>> for AstData<'doc> {
+    type Output = FieldDefaults;
+
+    fn parse_directives(&mut self, input: &'doc SchemaDefinition<'doc, &'doc str>) -> Self::Output {
+        let mut defaults = FieldDefaults::default();
+
+        for dir in &input.directives {
+            // Other directives are checked by `CodeGenPass`
+            if dir.name != "juniper" {
+                continue;
+            }
+
+            match JuniperDirective::<(
+                Option<Ownership>,
+                Option<Infallible>,
+                Option<Async>,
+                Option<ErrorType>,
+                Option<ArgsStruct>,
+                Option<ExecutorParam>,
+            )>::from_directive(dir)
+            {
+                Ok(x) => {
+                    defaults = FieldDefaults {
+                        ownership: x.args.0,
+                        infallible: x.args.1,
+                        r#async: x.args.2,
+                        error_type: x.args.3,
+                        args_struct: x.args.4,
+                        executor: x.args.5,
+                    };
+                }
+                Err(err) => {
+                    self.emit_error(dir.position, err);
+                }
+            }
+        }
+
+        defaults
+    }
+}
+
+impl<'doc> ParseDirective<&'doc ScalarType<'doc, &'doc str>> for CodeGenPass<'doc> {
+    type Output = (Option<SpecifiedBy>, Option<Vec<syn::Path>>);
+
+    fn parse_directives(&mut self, input: &'doc ScalarType<'doc, &'doc str>) -> Self::Output {
+        let mut specified_by = None;
+        let mut derive = None;
+
+        for dir in &input.directives {
+            if dir.name == "specifiedBy" {
+                match SpecifiedBy::from_directive(dir) {
+                    Ok(x) => specified_by = Some(x),
+                    Err(err) => self.emit_error(dir.position, err),
+                }
+            } else if dir.name == "juniper" && input.name == crate::DATE_TIME_SCALAR_NAME {
+                // This is parsed by `AstData` since it affects which type is generated
+                continue;
+            } else if dir.name == "juniper" && !is_special_case_scalar(input.name) {
+                match JuniperDirective::<(Option<RustName>, Option<Derive>)>::from_directive(dir) {
+                    Ok(JuniperDirective {
+                        args: (_, Some(Derive { value })),
+                        ..
+                    }) => derive = Some(value),
+                    Ok(_) => {}
+                    Err(err) => self.emit_error(dir.position, err),
+                }
+            } else if self.ast_data.is_custom_directive(dir.name) {
+                continue;
+            } else {
+                self.emit_error(
+                    dir.position,
+                    ErrorKind::UnknownDirective {
+                        suggestions: vec![],
+                    },
+                );
+            }
+        }
+
+        (specified_by, derive)
+    }
+}
+
+macro_rules! juniper_parsed_by_ast_data {
     ($ty:ty) => {
         impl<'doc> ParseDirective<&'doc $ty> for CodeGenPass<'doc> {
             type Output = ();
 
             fn parse_directives(&mut self, input: &'doc $ty) -> Self::Output {
                 for directive in &input.directives {
+                    // The field defaults are needed before the type is visited so they're parsed
+                    // by `AstData`
+                    if directive.name == "juniper"
+                        || self.ast_data.is_custom_directive(directive.name)
+                    {
+                        continue;
+                    }
+
                     self.emit_error(
                         directive.position,
                         ErrorKind::UnknownDirective {
@@ -522,11 +1181,71 @@ macro_rules! supports_no_directives {
     };
 }
 
-supports_no_directives!(SchemaDefinition<'doc, &'doc str>);
-supports_no_directives!(ScalarType<'doc, &'doc str>);
-supports_no_directives!(ObjectType<'doc, &'doc str>);
-supports_no_directives!(InterfaceType<'doc, &'doc str>);
-supports_no_directives!(UnionType<'doc, &'doc str>);
-supports_no_directives!(EnumType<'doc, &'doc str>);
-supports_no_directives!(InputObjectType<'doc, &'doc str>);
-supports_no_directives!(InputValue<'doc, &'doc str>);
+juniper_parsed_by_ast_data!(SchemaDefinition<'doc, &'doc str>);
+juniper_parsed_by_ast_data!(ObjectType<'doc, &'doc str>);
+juniper_parsed_by_ast_data!(InterfaceType<'doc, &'doc str>);
+
+macro_rules! supports_only_rust_name {
+    ($ty:ty) => {
+        impl<'doc> ParseDirective<&'doc $ty> for CodeGenPass<'doc> {
+            type Output = ();
+
+            fn parse_directives(&mut self, input: &'doc $ty) -> Self::Output {
+                for directive in &input.directives {
+                    if self.ast_data.is_custom_directive(directive.name) {
+                        continue;
+                    }
+
+                    // Type names are looked up through `AstData` since types can be used before
+                    // they're defined, so this only checks the directive
+                    if let Err(err) =
+                        JuniperDirective::<Option<RustName>>::from_directive(directive)
+                    {
+                        self.emit_error(directive.position, err);
+                    }
+                }
+            }
+        }
+    };
+}
+
+supports_only_rust_name!(UnionType<'doc, &'doc str>);
+
+macro_rules! supports_rust_name_rust_type_and_derive {
+    ($ty:ty) => {
+        impl<'doc> ParseDirective<&'doc $ty> for CodeGenPass<'doc> {
+            type Output = Option<Vec<syn::Path>>;
+
+            fn parse_directives(&mut self, input: &'doc $ty) -> Self::Output {
+                let mut derive = None;
+
+                for directive in &input.directives {
+                    if self.ast_data.is_custom_directive(directive.name) {
+                        continue;
+                    }
+
+                    // `rust_name` and `rust_type` are looked up through `AstData` since types can
+                    // be used before they're defined, so they're only checked here
+                    match JuniperDirective::<(
+                                        Option<RustName>,
+                                        Option<RustType>,
+                                        Option<Derive>,
+                                    )>::from_directive(directive)
+                                    {
+                                        Ok(JuniperDirective {
+                                            args: (_, _, Some(Derive { value })),
+                                            ..
+                                        }) => derive = Some(value),
+                                        Ok(_) => {}
+                                        Err(err) => self.emit_error(directive.position, err),
+                                    }
+                }
+
+                derive
+            }
+        }
+    };
+}
+
+supports_rust_name_rust_type_and_derive!(EnumType<'doc, &'doc str>);
+supports_rust_name_rust_type_and_derive!(InputObjectType<'doc, &'doc str>);
@@ -89,6 +89,24 @@ impl fmt::Display for Deprecation {
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum SpecifiedBy {
+    WrongNumberOfArgs(usize),
+    InvalidKey(String),
+}
+
+impl fmt::Display for SpecifiedBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SpecifiedBy::*;
+        match self {
+            WrongNumberOfArgs(count) => {
+                write!(f, "Wrong number of args. Expected 1, got `{}`", count)
+            }
+            InvalidKey(key) => write!(f, "Invalid key. Expected `url`, got `{}`", key),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub enum ValueType {
     Variable,
@@ -145,13 +163,32 @@ impl fmt::Display for Ownership {
         match self {
             Self::InvalidValue(name) => write!(
                 f,
-                "Invalid value. Expected `owned`, `borrowed`, or `as_ref`, got `{}`",
+                "Invalid value. Expected `owned`, `borrowed`, `as_ref`, `arc`, `str`, `cow`, `slice`, or `iter`, got `{}`",
                 name
             ),
         }
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum RustName {
+    InvalidValue(String),
+}
+
+impl fmt::Display for RustName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidValue(name) => {
+                write!(
+                    f,
+                    "Invalid value. `{}` is not a valid Rust identifier",
+                    name
+                )
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Juniper {
     InvalidName(String),
@@ -168,7 +205,9 @@ impl fmt::Display for Juniper {
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub enum UnsupportedDirectiveKind {
     Deprecation(Deprecation),
+    SpecifiedBy(SpecifiedBy),
     Ownership(Ownership),
+    RustName(RustName),
     Juniper(Juniper),
     InvalidType {
         actual: ValueType,
@@ -180,7 +219,9 @@ impl fmt::Display for UnsupportedDirectiveKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::Deprecation(inner) => write!(f, "{}", inner),
+            Self::SpecifiedBy(inner) => write!(f, "{}", inner),
             Self::Ownership(inner) => write!(f, "{}", inner),
+            Self::RustName(inner) => write!(f, "{}", inner),
             Self::Juniper(inner) => write!(f, "{}", inner),
             Self::InvalidType { expected, actual } => {
                 write!(f, "Invalid type. Expected `{}`, got `{}`", expected, actual)
@@ -213,17 +254,52 @@ pub enum ErrorKind {
         field_type_b: String,
     },
     VariableDefaultValue,
-    InputTypeFieldWithDefaultValue,
+    RecursiveInputFieldDefaultValue,
     AsRefOwnershipForNamedType,
+    UnsupportedOwnershipForType {
+        ownership: &'static str,
+        supported_types: &'static str,
+    },
+    IterOwnershipOnAsyncField,
+    LifetimeOnRootType,
     FieldNameInSnakeCase,
     UppercaseUuidScalar,
     InvalidJuniperDirective(String, Option<String>),
     CannotDeclareBuiltinAsScalar,
     InvalidStreamReturnType(String),
+    InvalidErrorType(String),
+    InvalidRustPath(String),
+    InvalidDerive(String),
+    InvalidNullableKind(String),
+    TristateNotNullable,
     StreamTypeNotSupportedHere,
     StreamItemInfallibleNotSupportedHere,
     SubscriptionsCannotImplementInterfaces,
     SubscriptionFieldMustBeOwned,
+    DeprecatedRequiredInputValue,
+    VariableInCustomDirective,
+    UnknownCustomDirectiveArgument {
+        directive: String,
+        argument: String,
+    },
+    ReservedRustName {
+        name: String,
+        rust_name: String,
+    },
+    RustNameCollision {
+        name: String,
+        other: String,
+        rust_name: String,
+    },
+    GeneratedNameCollision {
+        name: String,
+        generated_for: String,
+    },
+    InterfaceFieldSettingMismatch {
+        setting: &'static str,
+        interface_field: String,
+        implementor_field: String,
+    },
 }
 
 impl ErrorKind {
@@ -262,13 +338,27 @@ impl ErrorKind {
                 "Error while generating `QueryTrail` for union `{}`",
                 union_name
             ),
-            ErrorKind::InputTypeFieldWithDefaultValue => {
-                "Default values for input type fields are not supported".to_string()
+            ErrorKind::RecursiveInputFieldDefaultValue => {
+                "Default value of input field contains the field itself".to_string()
             }
             ErrorKind::AsRefOwnershipForNamedType => {
                 "@juniper(ownership: \"as_ref\") is only supported on `Option` and `Vec` types"
                     .to_string()
             }
+            ErrorKind::UnsupportedOwnershipForType {
+                ownership,
+                supported_types,
+            } => format!(
+                "@juniper(ownership: \"{}\") is only supported on {}",
+                ownership, supported_types
+            ),
+            ErrorKind::IterOwnershipOnAsyncField => {
+                "@juniper(ownership: \"iter\") is not supported on async fields".to_string()
+            }
+            ErrorKind::LifetimeOnRootType => {
+                "@juniper(lifetime: true) is not supported on the query, mutation, and subscription types"
+                    .to_string()
+            }
             ErrorKind::FieldNameInSnakeCase => {
                 "Field names must be camelCase, not snake_case".to_string()
             }
@@ -284,6 +374,19 @@ impl ErrorKind {
             ErrorKind::InvalidStreamReturnType(_) => {
                 "Invalid stream return type. This doesn't seem to be a valid Rust type".to_string()
             }
+            ErrorKind::InvalidErrorType(_) => {
+                "Invalid error type. This doesn't seem to be a valid Rust type".to_string()
+            }
+            ErrorKind::InvalidRustPath(_) => {
+                "Invalid Rust path. This doesn't seem to be a valid path to a Rust type".to_string()
+            }
+            ErrorKind::InvalidDerive(_) => "Invalid `derive` directive argument".to_string(),
+            ErrorKind::InvalidNullableKind(value) => {
+                format!("Invalid value for `nullable_kind`: `{}`", value)
+            }
+            ErrorKind::TristateNotNullable => {
+                "`nullable_kind: \"tristate\"` is only supported on nullable arguments and input fields without default values".to_string()
+            }
             ErrorKind::StreamTypeNotSupportedHere => {
                 "`stream_type` directive argument is only supported on subscription fields".to_string()
             }
@@ -294,8 +397,45 @@ impl ErrorKind {
                 "Subscriptions cannot implement interfaces".to_string()
             }
             ErrorKind::SubscriptionFieldMustBeOwned => {
-                "Subscription fields must use `@juniper(ownership: \"owned\")`".to_string()
+                "Subscription fields must use `@juniper(ownership: \"owned\")` or `@juniper(ownership: \"arc\")`".to_string()
+            }
+            ErrorKind::DeprecatedRequiredInputValue => {
+                "Required arguments and input fields cannot be deprecated".to_string()
             }
+            ErrorKind::VariableInCustomDirective => {
+                "Directive arguments cannot refer to variables".to_string()
+            }
+            ErrorKind::UnknownCustomDirectiveArgument { directive, argument } => format!(
+                "Unknown argument `{}` for directive `@{}`",
+                argument, directive
+            ),
+            ErrorKind::ReservedRustName { name, rust_name } => format!(
+                "`{}` would be called `{}` in Rust, which is a reserved name",
+                name, rust_name
+            ),
+            ErrorKind::RustNameCollision {
+                name,
+                other,
+                rust_name,
+            } => format!(
+                "`{}` and `{}` would both be called `{}` in Rust",
+                name, other, rust_name
+            ),
+            ErrorKind::GeneratedNameCollision {
+                name,
+                generated_for,
+            } => format!(
+                "`{}` collides with the code generated for {}",
+                name, generated_for
+            ),
+            ErrorKind::InterfaceFieldSettingMismatch {
+                setting,
+                interface_field,
+                implementor_field,
+            } => format!(
+                "`{}` must use the same `{}` setting as `{}`",
+                implementor_field, setting, interface_field
+            ),
         }
     }
 
@@ -342,20 +482,9 @@ impl ErrorKind {
             ErrorKind::DateScalarNotDefined => {
                 Some("Insert `scalar Date` into your schema".to_string())
             }
-            ErrorKind::InputTypeFieldWithDefaultValue => {
-                let mut f = String::new();
-                writeln!(f, "Consider using default field arguments instead");
-                writeln!(f);
-                writeln!(f, "It is not supported because the spec isn't clear");
-                writeln!(f, "about what should happen when there are defaults");
-                writeln!(f, "in both the input type definition and field argument");
-                writeln!(f);
-                writeln!(
-                    f,
-                    "See https://github.com/webonyx/graphql-php/issues/350 for an example"
-                );
-                Some(f)
-            }
+            ErrorKind::RecursiveInputFieldDefaultValue => Some(
+                "Leave the field out of the default value or give it a value explicitly".to_string(),
+            ),
             ErrorKind::FieldNameInSnakeCase => Some(
                 "This is because Juniper always converts all field names to camelCase".to_string(),
             ),
@@ -371,7 +500,24 @@ impl ErrorKind {
                 Some("This is to be consistent with the naming the \"uuid\" crate".to_string())
             }
             ErrorKind::InvalidJuniperDirective(_, notes) => notes.to_owned(),
-            ErrorKind::InvalidStreamReturnType(syn_error) => Some(syn_error.to_owned()),
+            ErrorKind::InvalidStreamReturnType(syn_error)
+            | ErrorKind::InvalidErrorType(syn_error)
+            | ErrorKind::InvalidRustPath(syn_error)
+            | ErrorKind::InvalidDerive(syn_error) => Some(syn_error.to_owned()),
+            ErrorKind::InvalidNullableKind(_) => {
+                Some("Supported values are `option` and `tristate`".to_string())
+            }
+            ErrorKind::DeprecatedRequiredInputValue => Some(
+                "Clients would have no way to stop sending it. Make it nullable or give it a default value first".to_string(),
+            ),
+            ErrorKind::ReservedRustName { .. }
+            | ErrorKind::RustNameCollision { .. }
+            | ErrorKind::GeneratedNameCollision { .. } => {
+                Some("Rename it in the schema or give it another Rust name with `@juniper(rust_name: \"...\")`".to_string())
+            }
+            ErrorKind::InterfaceFieldSettingMismatch { .. } => Some(
+                "Types implementing an interface must use the same settings as the interface for the interface's fields".to_string(),
+            ),
             _ => None,
         }
     }
@@ -1,16 +1,30 @@
 pub mod code_gen_pass;
+pub mod derives;
 pub mod directive_parsing;
 pub mod error;
+pub mod naming;
 pub mod schema_visitor;
 pub mod validations;
 
 use self::{
-    directive_parsing::{DateTimeScalarType, ParseDirective},
+    directive_parsing::{
+        args_struct, rust_name, rust_type, DateTimeScalarType, FieldDefaults, Lifetime,
+        ParseDirective,
+    },
     error::{Error, ErrorKind},
     schema_visitor::{visit_document, SchemaVisitor},
 };
 use graphql_parser::{schema::*, Pos};
+use quote::format_ident;
 use std::collections::{BTreeSet, HashMap, HashSet};
+use syn::Ident;
+
+pub fn is_non_null<'doc>(type_: &Type<'doc, &'doc str>) -> bool {
+    match type_ {
+        Type::NonNullType(_) => true,
+        Type::NamedType(_) | Type::ListType(_) => false,
+    }
+}
 
 pub fn type_name<'doc>(type_: &Type<'doc, &'doc str>) -> &'doc str {
     match &*type_ {
@@ -20,12 +34,49 @@ pub fn type_name<'doc>(type_: &Type<'doc, &'doc str>) -> &'doc str {
     }
 }
 
+/// Scalars that are mapped to types from other crates rather than generated.
+pub fn is_special_case_scalar(name: &str) -> bool {
+    name == crate::DATE_TIME_SCALAR_NAME
+        || name == crate::DATE_SCALAR_NAME
+        || name == crate::URL_SCALAR_NAME
+        || name == crate::UUID_SCALAR_NAME
+}
+
+/// Rust keywords. GraphQL names matching these have to be generated as raw identifiers.
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+/// Names that cannot be used as Rust identifiers, not even raw ones.
+pub const RESERVED_RUST_NAMES: &[&str] = &["self", "Self", "super", "crate", "_"];
+
+/// Make an identifier for a name from the schema, using a raw identifier if the name is a Rust
+/// keyword.
+pub fn rust_ident(name: &str) -> Ident {
+    if RUST_KEYWORDS.contains(&name) {
+        format_ident!("r#{}", name)
+    } else if RESERVED_RUST_NAMES.contains(&name) {
+        // These are reported as errors by `RustNameValidator`. Returning something valid avoids
+        // panicking before the error is shown.
+        format_ident!("{}_", name)
+    } else {
+        format_ident!("{}", name)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum TypeKind {
     Scalar,
     Type,
 }
 
+/// Directives that have special meaning and therefore cannot be declared as custom directives.
+const BUILT_IN_DIRECTIVES: &[&str] = &["juniper", "deprecated", "specifiedBy", "skip", "include"];
+
 pub trait EmitError {
     fn emit_error(&mut self, pos: Pos, kind: ErrorKind);
 }
@@ -42,15 +93,39 @@ pub struct AstData<'doc> {
     interface_implementors: HashMap<&'doc str, Vec<&'doc str>>,
     user_scalars: HashSet<&'doc str>,
     enum_types: HashSet<&'doc str>,
-    union_types: HashSet<&'doc str>,
-    input_object_field_types: HashMap<&'doc str, HashMap<&'doc str, &'doc Type<'doc, &'doc str>>>,
+    union_types: HashMap<&'doc str, &'doc [&'doc str]>,
+    input_object_types: HashMap<&'doc str, &'doc InputObjectType<'doc, &'doc str>>,
     errors: BTreeSet<Error>,
     include_time_zone_on_date_time_scalar: bool,
-    subscription_type_name: Option<&'doc str>,
+    object_types: HashMap<&'doc str, &'doc ObjectType<'doc, &'doc str>>,
+    schema_definition: Option<&'doc SchemaDefinition<'doc, &'doc str>>,
+    root_types: RootTypes<'doc>,
+    custom_directives: HashMap<&'doc str, &'doc DirectiveDefinition<'doc, &'doc str>>,
+    type_rust_names: HashMap<&'doc str, &'doc str>,
+    member_rust_names: HashMap<(&'doc str, &'doc str), &'doc str>,
+    schema_field_defaults: FieldDefaults,
+    type_field_defaults: HashMap<&'doc str, FieldDefaults>,
+    lifetime_types: HashSet<&'doc str>,
+    type_rust_paths: HashMap<&'doc str, syn::Path>,
 }
 
 impl<'doc> SchemaVisitor<'doc> for AstData<'doc> {
     fn visit_object_type(&mut self, obj: &'doc ObjectType<'doc, &'doc str>) {
+        self.object_types.insert(&obj.name, obj);
+
+        let directives = self.parse_directives(obj);
+        self.type_field_defaults
+            .insert(obj.name, directives.field_defaults);
+        if let Some(Lifetime { value: true }) = directives.lifetime {
+            self.lifetime_types.insert(obj.name);
+        }
+        if let Some(path) = directives
+            .rust_path
+            .and_then(|path| syn::parse_str(&path.value).ok())
+        {
+            self.type_rust_paths.insert(obj.name, path);
+        }
+
         for interface in &obj.implements_interfaces {
             self.interface_implementors
                 .entry(interface)
@@ -59,6 +134,12 @@ impl<'doc> SchemaVisitor<'doc> for AstData<'doc> {
         }
     }
 
+    fn visit_interface_type(&mut self, interface: &'doc InterfaceType<'doc, &'doc str>) {
+        let directives = self.parse_directives(interface);
+        self.type_field_defaults
+            .insert(interface.name, directives.field_defaults);
+    }
+
     fn visit_scalar_type(&mut self, scalar: &'doc ScalarType<'doc, &'doc str>) {
         match &*scalar.name {
             name if name == crate::DATE_TIME_SCALAR_NAME => {
@@ -78,33 +159,91 @@ impl<'doc> SchemaVisitor<'doc> for AstData<'doc> {
 
     fn visit_enum_type(&mut self, enum_type: &'doc EnumType<'doc, &'doc str>) {
         self.enum_types.insert(&enum_type.name);
+
+        if let Some(path) = rust_type(&enum_type.directives) {
+            self.type_rust_paths.insert(enum_type.name, path);
+        }
+
+        for value in &enum_type.values {
+            if let Some(rust_name) = rust_name(&value.directives) {
+                self.member_rust_names
+                    .insert((enum_type.name, value.name), rust_name);
+            }
+        }
     }
 
     fn visit_union_type(&mut self, union_type: &'doc UnionType<'doc, &'doc str>) {
-        self.union_types.insert(&union_type.name);
+        self.union_types.insert(&union_type.name, &union_type.types);
     }
 
     fn visit_input_object_type(&mut self, input_type: &'doc InputObjectType<'doc, &'doc str>) {
+        if let Some(path) = rust_type(&input_type.directives) {
+            self.type_rust_paths.insert(input_type.name, path);
+        }
+
+        self.input_object_types.insert(&input_type.name, input_type);
+
         for field in &input_type.fields {
-            self.input_object_field_types
-                .entry(&input_type.name)
-                .or_insert_with(HashMap::new)
-                .insert(&field.name, &field.value_type);
+            if let Some(rust_name) = rust_name(&field.directives) {
+                self.member_rust_names
+                    .insert((input_type.name, field.name), rust_name);
+            }
         }
     }
 
-    fn visit_schema_definition(&mut self, node: &'doc SchemaDefinition<'doc, &'doc str>) {
-        if let Some(subscription_type_name) = node.subscription {
-            self.subscription_type_name = Some(subscription_type_name);
+    fn visit_type_definition(&mut self, type_def: &'doc TypeDefinition<'doc, &'doc str>) {
+        let (name, directives) = match type_def {
+            TypeDefinition::Scalar(def) => (def.name, &def.directives),
+            TypeDefinition::Object(def) => (def.name, &def.directives),
+            TypeDefinition::Interface(def) => (def.name, &def.directives),
+            TypeDefinition::Union(def) => (def.name, &def.directives),
+            TypeDefinition::Enum(def) => (def.name, &def.directives),
+            TypeDefinition::InputObject(def) => (def.name, &def.directives),
+        };
+
+        if let Some(rust_name) = rust_name(directives) {
+            self.type_rust_names.insert(name, rust_name);
         }
     }
+
+    fn visit_schema_definition(&mut self, node: &'doc SchemaDefinition<'doc, &'doc str>) {
+        self.schema_definition = Some(node);
+        self.schema_field_defaults = self.parse_directives(node);
+        self.root_types = RootTypes {
+            query: node.query,
+            mutation: node.mutation,
+            subscription: node.subscription,
+        };
+    }
 }
 
 impl<'doc> AstData<'doc> {
     pub fn new_from_doc(doc: &'doc Document<'doc, &'doc str>) -> Result<Self, BTreeSet<Error>> {
         let mut data = Self::new();
+
+        // Custom directives have to be known before visiting since they can be used before
+        // they're defined
+        for def in &doc.definitions {
+            if let Definition::DirectiveDefinition(directive) = def {
+                if !BUILT_IN_DIRECTIVES.contains(&directive.name) {
+                    data.custom_directives.insert(directive.name, directive);
+                }
+            }
+        }
+
         visit_document(&mut data, doc);
 
+        // Per the spec, types named `Query`, `Mutation`, and `Subscription` are the root types
+        // if there is no schema definition
+        if data.schema_definition.is_none() {
+            let find_type = |name| data.object_types.get_key_value(name).map(|(name, _)| *name);
+            data.root_types = RootTypes {
+                query: find_type("Query"),
+                mutation: find_type("Mutation"),
+                subscription: find_type("Subscription"),
+            };
+        }
+
         if data.errors.is_empty() {
             Ok(data)
         } else {
@@ -118,10 +257,19 @@ impl<'doc> AstData<'doc> {
             user_scalars: Default::default(),
             enum_types: Default::default(),
             union_types: Default::default(),
-            input_object_field_types: Default::default(),
+            input_object_types: Default::default(),
             errors: Default::default(),
             include_time_zone_on_date_time_scalar: true,
-            subscription_type_name: None,
+            object_types: Default::default(),
+            schema_definition: None,
+            root_types: Default::default(),
+            custom_directives: Default::default(),
+            type_rust_names: Default::default(),
+            member_rust_names: Default::default(),
+            schema_field_defaults: Default::default(),
+            type_field_defaults: Default::default(),
+            lifetime_types: Default::default(),
+            type_rust_paths: Default::default(),
         }
     }
 
@@ -166,57 +314,147 @@ impl<'doc> AstData<'doc> {
     }
 
     pub fn is_union_type(&self, name: &str) -> bool {
-        self.union_types.contains(name)
+        self.union_types.contains_key(name)
     }
 
     pub fn is_interface_type(&self, name: &str) -> bool {
         self.interface_implementors.contains_key(name)
     }
 
-    #[allow(clippy::ptr_arg)]
-    pub fn input_object_field_is_nullable(
+    pub fn input_object_fields(
         &self,
-        input_type_name: &'doc str,
-        field_name: &'doc str,
-    ) -> Option<bool> {
-        use graphql_parser::query::Type::*;
-
-        let field_map = self.input_object_field_types.get(input_type_name)?;
-        let type_ = field_map.get(field_name)?;
-        match type_ {
-            NamedType(_) => Some(true),
-            ListType(_) => Some(true),
-            NonNullType(_) => Some(false),
-        }
+        input_type_name: &str,
+    ) -> Option<&'doc [InputValue<'doc, &'doc str>]> {
+        self.input_object_types
+            .get(input_type_name)
+            .map(|input_type| &input_type.fields[..])
     }
 
-    pub fn input_object_field_names(
+    pub fn object_field(
         &self,
-        input_type_name: &'doc str,
-    ) -> Option<HashSet<&'doc str>> {
-        let field_map = self.input_object_field_types.get(input_type_name)?;
-        let mut out = HashSet::new();
-        for key in field_map.keys() {
-            out.insert(*key);
-        }
-        Some(out)
+        object_name: &str,
+        field_name: &str,
+    ) -> Option<&'doc Field<'doc, &'doc str>> {
+        self.object_types
+            .get(object_name)?
+            .fields
+            .iter()
+            .find(|field| field.name == field_name)
+    }
+
+    /// Is `name` a directive declared in the schema that juniper-from-schema doesn't handle itself?
+    pub fn is_custom_directive(&self, name: &str) -> bool {
+        self.custom_directives.contains_key(name)
+    }
+
+    pub fn custom_directive_definition(
+        &self,
+        name: &str,
+    ) -> Option<&'doc DirectiveDefinition<'doc, &'doc str>> {
+        self.custom_directives.get(name).copied()
+    }
+
+    pub fn has_custom_directives(&self) -> bool {
+        !self.custom_directives.is_empty()
+    }
+
+    /// The name given with `@juniper(rust_name: "...")` on a type, if any.
+    pub fn type_rust_name(&self, type_name: &str) -> Option<&'doc str> {
+        self.type_rust_names.get(type_name).copied()
+    }
+
+    /// The path given with `@juniper(rust_path: "...")` on an object type or with
+    /// `@juniper(rust_type: "...")` on an enum or input object, if any.
+    pub fn type_rust_path(&self, type_name: &str) -> Option<&syn::Path> {
+        self.type_rust_paths.get(type_name)
+    }
+
+    /// The name given with `@juniper(rust_name: "...")` on an enum value or input object field,
+    /// if any.
+    pub fn member_rust_name(&self, type_name: &str, member_name: &str) -> Option<&'doc str> {
+        self.member_rust_names
+            .get(&(type_name, member_name))
+            .copied()
+    }
+
+    /// The `@juniper` settings for fields on the given type that don't set them, from the type
+    /// itself or the schema.
+    pub fn field_defaults(&self, type_name: &str) -> FieldDefaults {
+        self.type_field_defaults
+            .get(type_name)
+            .cloned()
+            .unwrap_or_default()
+            .or(self.schema_field_defaults.clone())
     }
 
-    pub fn input_object_field_type_name(
+    /// Whether the arguments of a field on the given type are passed in an args struct.
+    pub fn uses_args_struct(
         &self,
-        input_type_name: &'doc str,
-        field_name: &'doc str,
-    ) -> Option<&'doc str> {
-        let field_map = &self.input_object_field_types.get(input_type_name)?;
-        let type_ = field_map.get(field_name)?;
-        Some(type_name(&type_))
+        type_name: &str,
+        directives: &'doc [Directive<'doc, &'doc str>],
+    ) -> bool {
+        args_struct(directives)
+            .or_else(|| {
+                self.field_defaults(type_name)
+                    .args_struct
+                    .map(|args_struct| args_struct.value)
+            })
+            .unwrap_or(false)
+    }
+
+    /// Whether the Rust type generated for or used for the given object, interface, or union has
+    /// a lifetime parameter. Objects get one with `@juniper(lifetime: true)`, and interfaces and
+    /// unions get one if any of their implementors or members have one.
+    pub fn has_lifetime(&self, name: &str) -> bool {
+        if self.lifetime_types.contains(name) {
+            return true;
+        }
+
+        if let Some(members) = self.union_types.get(name) {
+            return members
+                .iter()
+                .any(|member| self.lifetime_types.contains(member));
+        }
+
+        self.get_implementors_of_interface(name)
+            .map(|implementors| {
+                implementors
+                    .iter()
+                    .any(|implementor| self.lifetime_types.contains(implementor))
+            })
+            .unwrap_or(false)
+    }
+
+    /// Whether the given type is the query, mutation, or subscription type.
+    pub fn is_root_type(&self, name: &str) -> bool {
+        let RootTypes {
+            query,
+            mutation,
+            subscription,
+        } = &self.root_types;
+        [query, mutation, subscription]
+            .iter()
+            .any(|root| **root == Some(name))
     }
 
     pub fn is_subscription_type(&self, name: &'doc str) -> bool {
-        self.subscription_type_name
+        self.root_types
+            .subscription
             .map(|s| s == name)
             .unwrap_or(false)
     }
+
+    /// The root types, either from the schema definition or inferred from the type names.
+    pub fn root_types(&self) -> &RootTypes<'doc> {
+        &self.root_types
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RootTypes<'doc> {
+    pub query: Option<&'doc str>,
+    pub mutation: Option<&'doc str>,
+    pub subscription: Option<&'doc str>,
 }
 
 impl<'doc> EmitError for AstData<'doc> {
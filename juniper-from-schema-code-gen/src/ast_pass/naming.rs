@@ -0,0 +1,108 @@
+use super::rust_ident;
+use heck::{CamelCase, SnakeCase};
+use quote::format_ident;
+use std::str::FromStr;
+use syn::{ext::IdentExt, Ident};
+
+/// How names from the schema are turned into Rust names.
+///
+/// Names set with `@juniper(rust_name: "...")` are used as is. The GraphQL names are always kept
+/// in the schema served by juniper.
+#[derive(Debug, Clone)]
+pub struct Naming {
+    /// Appended to object names to get the name of the trait with their fields.
+    pub fields_trait_suffix: String,
+    /// Prepended to field names to get the names of the trait methods.
+    pub field_method_prefix: String,
+    /// How acronyms in type names are handled.
+    pub acronyms: Acronyms,
+}
+
+impl Default for Naming {
+    fn default() -> Self {
+        Self {
+            fields_trait_suffix: "Fields".to_string(),
+            field_method_prefix: "field_".to_string(),
+            acronyms: Acronyms::Keep,
+        }
+    }
+}
+
+/// How acronyms in type names are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Acronyms {
+    /// Use type names as they're written in the schema, so `URL` stays `URL`.
+    Keep,
+    /// Convert type names to CamelCase, so `URL` becomes `Url` and `HTTPRequest` becomes
+    /// `HttpRequest`.
+    CamelCase,
+}
+
+impl FromStr for Acronyms {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "keep" => Ok(Acronyms::Keep),
+            "camel_case" => Ok(Acronyms::CamelCase),
+            other => Err(format!(
+                "Invalid value for `acronyms`. Expected `keep` or `camel_case`, got `{}`",
+                other
+            )),
+        }
+    }
+}
+
+/// Check that a fields trait suffix or field method prefix can be part of a Rust identifier.
+pub fn validate_affix(affix: &str) -> Result<(), String> {
+    let valid = affix.chars().all(|c| c == '_' || c.is_ascii_alphanumeric());
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "`{}` can't be part of a Rust identifier. Only use letters, digits, and `_`",
+            affix
+        ))
+    }
+}
+
+impl Naming {
+    /// The Rust name of an object, interface, union, enum, input object, or scalar.
+    pub fn type_name(&self, name: &str, rust_name: Option<&str>) -> String {
+        match (rust_name, self.acronyms) {
+            (Some(rust_name), _) => rust_name.to_string(),
+            (None, Acronyms::Keep) => name.to_string(),
+            (None, Acronyms::CamelCase) => name.to_camel_case(),
+        }
+    }
+
+    /// The Rust name of a field, argument, or input object field.
+    pub fn field_name(&self, name: &str, rust_name: Option<&str>) -> String {
+        rust_name
+            .map(|rust_name| rust_name.to_string())
+            .unwrap_or_else(|| name.to_snake_case())
+    }
+
+    /// The Rust name of an enum value.
+    pub fn enum_value_name(&self, name: &str, rust_name: Option<&str>) -> String {
+        rust_name
+            .map(|rust_name| rust_name.to_string())
+            .unwrap_or_else(|| name.to_camel_case())
+    }
+
+    /// The name of the trait with the fields of an object.
+    pub fn fields_trait_name(&self, type_name: &Ident) -> Ident {
+        format_ident!("{}{}", type_name, self.fields_trait_suffix)
+    }
+
+    /// The name of the trait method that resolves a field.
+    pub fn field_method_name(&self, field_name: &Ident) -> Ident {
+        // The prefix might be empty, in which case the method name can be a keyword
+        rust_ident(&format!(
+            "{}{}",
+            self.field_method_prefix,
+            field_name.unraw()
+        ))
+    }
+}
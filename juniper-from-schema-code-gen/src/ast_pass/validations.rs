@@ -1,11 +1,18 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 
-use super::{error::Error, schema_visitor::SchemaVisitor, EmitError, ErrorKind};
+use super::{
+    directive_parsing::{rust_name, rust_type},
+    error::Error,
+    is_non_null,
+    naming::Naming,
+    schema_visitor::SchemaVisitor,
+    EmitError, ErrorKind, RESERVED_RUST_NAMES,
+};
 use graphql_parser::{
     schema::{self, *},
     Pos,
 };
-use heck::SnakeCase;
+use heck::{CamelCase, SnakeCase};
 
 pub struct FieldNameCaseValidator {
     pub errors: BTreeSet<Error>,
@@ -70,6 +77,268 @@ impl<'doc> SchemaVisitor<'doc> for UuidNameCaseValidator {
     }
 }
 
+/// Checks that the names in the schema can be turned into Rust names that don't collide with
+/// each other or with the code we generate.
+///
+/// Names that are Rust keywords are fine since they're generated as raw identifiers.
+pub struct RustNameValidator {
+    schema_type_name: String,
+    naming: Naming,
+    pub errors: BTreeSet<Error>,
+}
+
+impl RustNameValidator {
+    pub fn new(schema_type_name: String, naming: Naming) -> Self {
+        Self {
+            schema_type_name,
+            naming,
+            errors: Default::default(),
+        }
+    }
+}
+
+/// Arguments become parameters of the resolver methods, next to these.
+const RESOLVER_PARAMS: &[&str] = &["executor", "trail"];
+
+impl<'doc> SchemaVisitor<'doc> for RustNameValidator {
+    fn visit_document(&mut self, doc: &'doc Document<'doc, &'doc str>) {
+        let mut generated_names = HashMap::new();
+        generated_names.insert(self.schema_type_name.clone(), "the schema type".to_string());
+
+        let types = doc
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                Definition::TypeDefinition(type_def) => Some(type_def),
+                _ => None,
+            })
+            .map(|type_def| {
+                let (name, pos, directives) = type_name_position_and_directives(type_def);
+                let rust_name = self.naming.type_name(name, rust_name(directives));
+                (type_def, name, rust_name, pos)
+            })
+            .collect::<Vec<_>>();
+
+        for (type_def, name, type_rust_name, _) in &types {
+            let suffixes: &[&str] = match type_def {
+                TypeDefinition::Object(_) => &[&self.naming.fields_trait_suffix],
+                TypeDefinition::Interface(_) => &[&self.naming.fields_trait_suffix, "Interface"],
+                TypeDefinition::InputObject(input) if has_builder(input) => &["Builder"],
+                _ => &[],
+            };
+
+            for suffix in suffixes {
+                generated_names.insert(
+                    format!("{}{}", type_rust_name, suffix),
+                    format!("`{}`", name),
+                );
+            }
+
+            // Fields with arguments get a struct for them, used by the query trail's `*_args`
+            // method and with `@juniper(args_struct: true)`
+            let fields: &[Field<'doc, &'doc str>] = match type_def {
+                TypeDefinition::Object(obj) => &obj.fields,
+                TypeDefinition::Interface(interface) => &interface.fields,
+                _ => &[],
+            };
+            for field in fields.iter().filter(|field| !field.arguments.is_empty()) {
+                let field_name = self
+                    .naming
+                    .field_name(field.name, rust_name(&field.directives));
+                generated_names.insert(
+                    format!("{}{}Args", type_rust_name, field_name.to_camel_case()),
+                    format!("`{}.{}`", name, field.name),
+                );
+            }
+        }
+
+        let mut seen = HashMap::<&str, &str>::new();
+
+        for (_, name, rust_name, pos) in &types {
+            if RESERVED_RUST_NAMES.contains(&rust_name.as_str()) {
+                self.errors.emit_error(
+                    *pos,
+                    ErrorKind::ReservedRustName {
+                        name: name.to_string(),
+                        rust_name: rust_name.clone(),
+                    },
+                );
+            }
+
+            if let Some(generated_for) = generated_names.get(rust_name) {
+                self.errors.emit_error(
+                    *pos,
+                    ErrorKind::GeneratedNameCollision {
+                        name: name.to_string(),
+                        generated_for: generated_for.clone(),
+                    },
+                );
+            }
+
+            if let Some(other) = seen.get(rust_name.as_str()) {
+                self.errors.emit_error(
+                    *pos,
+                    ErrorKind::RustNameCollision {
+                        name: name.to_string(),
+                        other: other.to_string(),
+                        rust_name: rust_name.clone(),
+                    },
+                );
+            } else {
+                seen.insert(rust_name, name);
+            }
+        }
+    }
+
+    fn visit_object_type(&mut self, ty: &'doc schema::ObjectType<'doc, &'doc str>) {
+        self.validate_fields(&ty.fields);
+    }
+
+    fn visit_interface_type(&mut self, ty: &'doc schema::InterfaceType<'doc, &'doc str>) {
+        self.validate_fields(&ty.fields);
+    }
+
+    fn visit_input_object_type(&mut self, ty: &'doc schema::InputObjectType<'doc, &'doc str>) {
+        self.validate_input_values(&ty.fields, &[]);
+
+        // Nullable fields get a setter on the builder, next to `build`
+        if has_builder(ty) {
+            let naming = &self.naming;
+            let names = ty
+                .fields
+                .iter()
+                .filter(|field| !is_non_null(&field.value_type))
+                .map(|field| {
+                    let rust_name = naming.field_name(field.name, rust_name(&field.directives));
+                    (field.name, rust_name, field.position)
+                })
+                .filter(|(_, rust_name, _)| rust_name == "build")
+                .collect::<Vec<_>>();
+
+            let type_name = naming.type_name(ty.name, rust_name(&ty.directives));
+
+            for (name, _, pos) in names {
+                self.errors.emit_error(
+                    pos,
+                    ErrorKind::GeneratedNameCollision {
+                        name: name.to_string(),
+                        generated_for: format!("the `build` method of `{}Builder`", type_name),
+                    },
+                );
+            }
+        }
+    }
+
+    fn visit_enum_type(&mut self, ty: &'doc schema::EnumType<'doc, &'doc str>) {
+        let naming = &self.naming;
+        let names = ty
+            .values
+            .iter()
+            .map(|value| {
+                let rust_name = naming.enum_value_name(value.name, rust_name(&value.directives));
+                (value.name, rust_name, value.position)
+            })
+            .collect::<Vec<_>>();
+        self.validate_names(names.into_iter(), &[]);
+    }
+}
+
+impl RustNameValidator {
+    fn validate_fields<'doc>(&mut self, fields: &'doc [Field<'doc, &'doc str>]) {
+        let naming = &self.naming;
+        let names = fields
+            .iter()
+            .map(|field| {
+                let rust_name = naming.field_name(field.name, rust_name(&field.directives));
+                (field.name, rust_name, field.position)
+            })
+            .collect::<Vec<_>>();
+        self.validate_names(names.into_iter(), &[]);
+
+        for field in fields {
+            self.validate_input_values(&field.arguments, RESOLVER_PARAMS);
+        }
+    }
+
+    fn validate_input_values<'doc>(
+        &mut self,
+        values: &'doc [InputValue<'doc, &'doc str>],
+        generated_names: &[&str],
+    ) {
+        let naming = &self.naming;
+        let names = values
+            .iter()
+            .map(|value| {
+                let rust_name = naming.field_name(value.name, rust_name(&value.directives));
+                (value.name, rust_name, value.position)
+            })
+            .collect::<Vec<_>>();
+        self.validate_names(names.into_iter(), generated_names);
+    }
+
+    fn validate_names<'doc>(
+        &mut self,
+        names: impl Iterator<Item = (&'doc str, String, Pos)>,
+        generated_names: &[&str],
+    ) {
+        let mut seen = HashMap::<String, &str>::new();
+
+        for (name, rust_name, pos) in names {
+            if RESERVED_RUST_NAMES.contains(&rust_name.as_str()) {
+                self.errors.emit_error(
+                    pos,
+                    ErrorKind::ReservedRustName {
+                        name: name.to_string(),
+                        rust_name: rust_name.clone(),
+                    },
+                );
+            }
+
+            if generated_names.contains(&rust_name.as_str()) {
+                self.errors.emit_error(
+                    pos,
+                    ErrorKind::GeneratedNameCollision {
+                        name: name.to_string(),
+                        generated_for: format!("the `{}` parameter", rust_name),
+                    },
+                );
+            }
+
+            if let Some(other) = seen.get(&rust_name) {
+                self.errors.emit_error(
+                    pos,
+                    ErrorKind::RustNameCollision {
+                        name: name.to_string(),
+                        other: other.to_string(),
+                        rust_name: rust_name.clone(),
+                    },
+                );
+            } else {
+                seen.insert(rust_name, name);
+            }
+        }
+    }
+}
+
+fn type_name_position_and_directives<'doc>(
+    type_def: &'doc TypeDefinition<'doc, &'doc str>,
+) -> (&'doc str, Pos, &'doc [Directive<'doc, &'doc str>]) {
+    match type_def {
+        TypeDefinition::Scalar(def) => (def.name, def.position, &def.directives),
+        TypeDefinition::Object(def) => (def.name, def.position, &def.directives),
+        TypeDefinition::Interface(def) => (def.name, def.position, &def.directives),
+        TypeDefinition::Union(def) => (def.name, def.position, &def.directives),
+        TypeDefinition::Enum(def) => (def.name, def.position, &def.directives),
+        TypeDefinition::InputObject(def) => (def.name, def.position, &def.directives),
+    }
+}
+
+/// Input objects with required fields get a builder, unless they're mapped onto an existing type.
+fn has_builder<'doc>(ty: &'doc InputObjectType<'doc, &'doc str>) -> bool {
+    rust_type(&ty.directives).is_none()
+        && ty.fields.iter().any(|field| is_non_null(&field.value_type))
+}
+
 fn is_snake_case(s: &str) -> bool {
     s.contains('_') && s.to_snake_case() == s
 }
@@ -0,0 +1,280 @@
+use crate::{parse_derives, validate_affix, Acronyms};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+use toml::Value;
+
+/// Name of the config file looked for next to `Cargo.toml`.
+pub const CONFIG_FILE_NAME: &str = "juniper-from-schema.toml";
+
+/// Settings read from a `juniper-from-schema.toml` file.
+///
+/// Every setting is optional. Settings given to the macro or builder directly take precedence.
+#[derive(Debug, Default, Clone)]
+pub struct Config {
+    pub context_type: Option<syn::Type>,
+    pub error_type: Option<syn::Type>,
+    pub schema_type_name: Option<syn::Ident>,
+    pub query_trails_module_name: Option<syn::Ident>,
+    pub visibility: Option<syn::Visibility>,
+    pub fields_trait_suffix: Option<String>,
+    pub field_method_prefix: Option<String>,
+    pub acronyms: Option<Acronyms>,
+    pub enum_derives: Option<Vec<syn::Path>>,
+    pub input_object_derives: Option<Vec<syn::Path>>,
+    pub scalar_derives: Option<Vec<syn::Path>>,
+}
+
+impl Config {
+    /// Read the config file at `path`.
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path).map_err(|err| ConfigError {
+            path: path.to_path_buf(),
+            kind: ConfigErrorKind::Io(err),
+        })?;
+
+        Self::parse(&contents).map_err(|kind| ConfigError {
+            path: path.to_path_buf(),
+            kind,
+        })
+    }
+
+    /// Read `juniper-from-schema.toml` from `CARGO_MANIFEST_DIR`, if there is one.
+    ///
+    /// Returns the path that was read along with the config.
+    pub fn from_manifest_dir() -> Result<Option<(PathBuf, Self)>, ConfigError> {
+        let dir = match std::env::var_os("CARGO_MANIFEST_DIR") {
+            Some(dir) => PathBuf::from(dir),
+            None => return Ok(None),
+        };
+        let path = dir.join(CONFIG_FILE_NAME);
+
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let config = Self::from_file(&path)?;
+        Ok(Some((path, config)))
+    }
+
+    fn parse(contents: &str) -> Result<Self, ConfigErrorKind> {
+        let table = match contents.parse::<Value>().map_err(ConfigErrorKind::Toml)? {
+            Value::Table(table) => table,
+            _ => unreachable!("toml documents are always tables"),
+        };
+
+        let mut config = Config::default();
+
+        for (key, value) in &table {
+            match key.as_str() {
+                "context_type" => {
+                    config.context_type = Some(parse_syn("context_type", value)?);
+                }
+                "error_type" => {
+                    config.error_type = Some(parse_syn("error_type", value)?);
+                }
+                "schema_type_name" => {
+                    config.schema_type_name = Some(parse_syn("schema_type_name", value)?);
+                }
+                "query_trails_module_name" => {
+                    config.query_trails_module_name =
+                        Some(parse_syn("query_trails_module_name", value)?);
+                }
+                "visibility" => {
+                    config.visibility = Some(parse_syn("visibility", value)?);
+                }
+                "fields_trait_suffix" => {
+                    config.fields_trait_suffix = Some(parse_affix("fields_trait_suffix", value)?);
+                }
+                "field_method_prefix" => {
+                    config.field_method_prefix = Some(parse_affix("field_method_prefix", value)?);
+                }
+                "acronyms" => {
+                    let acronyms = string_value("acronyms", value)?
+                        .parse()
+                        .map_err(|message| ConfigErrorKind::InvalidValue {
+                            key: "acronyms",
+                            message,
+                        })?;
+                    config.acronyms = Some(acronyms);
+                }
+                "enum_derives" => {
+                    config.enum_derives = Some(parse_derives_value("enum_derives", value)?);
+                }
+                "input_object_derives" => {
+                    config.input_object_derives =
+                        Some(parse_derives_value("input_object_derives", value)?);
+                }
+                "scalar_derives" => {
+                    config.scalar_derives = Some(parse_derives_value("scalar_derives", value)?);
+                }
+                other => return Err(ConfigErrorKind::UnknownKey(other.to_string())),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+fn string_value<'a>(key: &'static str, value: &'a Value) -> Result<&'a str, ConfigErrorKind> {
+    value.as_str().ok_or_else(|| ConfigErrorKind::InvalidValue {
+        key,
+        message: format!("Expected a string, got {}", value.type_str()),
+    })
+}
+
+fn parse_syn<T: syn::parse::Parse>(key: &'static str, value: &Value) -> Result<T, ConfigErrorKind> {
+    let value = string_value(key, value)?;
+    syn::parse_str(value).map_err(|err| ConfigErrorKind::InvalidValue {
+        key,
+        message: format!("Failed to parse `{}`: {}", value, err),
+    })
+}
+
+fn parse_affix(key: &'static str, value: &Value) -> Result<String, ConfigErrorKind> {
+    let value = string_value(key, value)?;
+    validate_affix(value).map_err(|message| ConfigErrorKind::InvalidValue { key, message })?;
+    Ok(value.to_string())
+}
+
+fn parse_derives_value(
+    key: &'static str,
+    value: &Value,
+) -> Result<Vec<syn::Path>, ConfigErrorKind> {
+    let value = string_value(key, value)?;
+    parse_derives(value).map_err(|message| ConfigErrorKind::InvalidValue { key, message })
+}
+
+/// Error returned when a config file couldn't be read.
+#[derive(Debug)]
+pub struct ConfigError {
+    path: PathBuf,
+    kind: ConfigErrorKind,
+}
+
+#[derive(Debug)]
+enum ConfigErrorKind {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+    UnknownKey(String),
+    InvalidValue { key: &'static str, message: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+        let path = manifest_dir
+            .and_then(|dir| self.path.strip_prefix(dir).ok().map(Path::to_path_buf))
+            .unwrap_or_else(|| self.path.clone());
+
+        write!(f, "Error in config file `{}`: ", path.display())?;
+
+        match &self.kind {
+            ConfigErrorKind::Io(err) => write!(f, "{}", err),
+            ConfigErrorKind::Toml(err) => write!(f, "{}", err),
+            ConfigErrorKind::UnknownKey(key) => write!(
+                f,
+                "Unknown key `{}`. Supported keys are `error_type`, `context_type`, `schema_type_name`, `query_trails_module_name`, `visibility`, `fields_trait_suffix`, `field_method_prefix`, `acronyms`, `enum_derives`, `input_object_derives`, and `scalar_derives`",
+                key
+            ),
+            ConfigErrorKind::InvalidValue { key, message } => {
+                write!(f, "Invalid value for `{}`. {}", key, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_all_keys() {
+        let config = Config::parse(
+            r#"
+            context_type = "crate::Context"
+            error_type = "MyError"
+            schema_type_name = "ApiSchema"
+            query_trails_module_name = "trails"
+            visibility = "pub(crate)"
+            fields_trait_suffix = "Resolvers"
+            field_method_prefix = "resolve_"
+            acronyms = "camel_case"
+            enum_derives = "strum::EnumString"
+            input_object_derives = "serde::Serialize, serde::Deserialize"
+            scalar_derives = "serde::Serialize"
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.context_type.is_some());
+        assert!(config.error_type.is_some());
+        assert_eq!(config.schema_type_name.unwrap(), "ApiSchema");
+        assert_eq!(config.query_trails_module_name.unwrap(), "trails");
+        assert!(config.visibility.is_some());
+        assert_eq!(config.fields_trait_suffix.unwrap(), "Resolvers");
+        assert_eq!(config.field_method_prefix.unwrap(), "resolve_");
+        assert_eq!(config.acronyms, Some(Acronyms::CamelCase));
+        assert_eq!(config.enum_derives.unwrap().len(), 1);
+        assert_eq!(config.input_object_derives.unwrap().len(), 2);
+        assert_eq!(config.scalar_derives.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn empty_file_sets_nothing() {
+        let config = Config::parse("").unwrap();
+
+        assert!(config.context_type.is_none());
+        assert!(config.acronyms.is_none());
+    }
+
+    #[test]
+    fn unknown_keys_are_errors() {
+        let err = Config::parse(r#"contxt_type = "Context""#).unwrap_err();
+
+        assert!(matches!(err, ConfigErrorKind::UnknownKey(key) if key == "contxt_type"));
+    }
+
+    #[test]
+    fn invalid_values_are_errors() {
+        let err = Config::parse(r#"error_type = "not a type""#).unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigErrorKind::InvalidValue {
+                key: "error_type",
+                ..
+            }
+        ));
+
+        let err = Config::parse("visibility = true").unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigErrorKind::InvalidValue {
+                key: "visibility",
+                ..
+            }
+        ));
+
+        let err =
+            Config::parse(r#"enum_derives = "serde::Serialize serde::Deserialize""#).unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigErrorKind::InvalidValue {
+                key: "enum_derives",
+                ..
+            }
+        ));
+
+        let err = Config::parse(r#"field_method_prefix = "field-""#).unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigErrorKind::InvalidValue {
+                key: "field_method_prefix",
+                ..
+            }
+        ));
+    }
+}
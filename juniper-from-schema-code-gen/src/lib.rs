@@ -11,16 +11,24 @@
 #![doc(html_root_url = "https://docs.rs/juniper-from-schema-code-gen/0.5.2")]
 
 mod ast_pass;
+mod config;
 
-use ast_pass::{code_gen_pass::CodeGenPass, error, AstData};
+use ast_pass::{
+    code_gen_pass::{CodeGenOptions, CodeGenPass},
+    derives::Derives,
+    error,
+    naming::Naming,
+    AstData,
+};
 
 use graphql_parser::parse_schema;
-use proc_macro2::Span;
-use quote::quote;
-use std::{
-    fmt,
-    path::{Path, PathBuf},
+use std::{fmt, path::PathBuf};
+
+pub use ast_pass::{
+    derives::parse_derives,
+    naming::{validate_affix, Acronyms},
 };
+pub use config::{Config, ConfigError, CONFIG_FILE_NAME};
 
 const DATE_TIME_SCALAR_NAME: &str = "DateTimeUtc";
 const DATE_SCALAR_NAME: &str = "Date";
@@ -30,8 +38,7 @@ const URL_SCALAR_NAME: &str = "Url";
 #[derive(Debug)]
 pub struct CodeGen {
     schema: SchemaLocation,
-    context_type: syn::Type,
-    error_type: syn::Type,
+    options: CodeGenOptions,
 }
 
 impl CodeGen {
@@ -40,6 +47,16 @@ impl CodeGen {
             schema: SchemaLocation::File(path),
             context_type: None,
             error_type: None,
+            schema_type_name: None,
+            query_trails_module_name: None,
+            visibility: None,
+            fields_trait_suffix: None,
+            field_method_prefix: None,
+            acronyms: None,
+            enum_derives: None,
+            input_object_derives: None,
+            scalar_derives: None,
+            config: Config::default(),
         }
     }
 
@@ -48,6 +65,16 @@ impl CodeGen {
             schema: SchemaLocation::Literal(schema),
             context_type: None,
             error_type: None,
+            schema_type_name: None,
+            query_trails_module_name: None,
+            visibility: None,
+            fields_trait_suffix: None,
+            field_method_prefix: None,
+            acronyms: None,
+            enum_derives: None,
+            input_object_derives: None,
+            scalar_derives: None,
+            config: Config::default(),
         }
     }
 
@@ -76,18 +103,14 @@ impl CodeGen {
             }
         };
 
-        let output = CodeGenPass::new(&schema, &self.error_type, &self.context_type, ast_data);
+        let output = CodeGenPass::new(&schema, schema_path.as_deref(), &self.options, ast_data);
 
         match output.gen_juniper_code(&doc) {
-            Ok(mut tokens) => {
+            Ok(tokens) => {
                 if debugging_enabled() {
                     eprintln!("{}", tokens);
                 }
 
-                if let Some(path) = schema_path {
-                    include_literal_schema(&mut tokens, path.as_path());
-                }
-
                 Ok(tokens)
             }
             Err(code_gen_errors) => {
@@ -101,21 +124,6 @@ impl CodeGen {
     }
 }
 
-// This should cause the Rust schema to be rebuild even if the user only changes the GraphQL schema
-// file.
-fn include_literal_schema(tokens: &mut proc_macro2::TokenStream, schema_path: &Path) {
-    let schema_path = syn::LitStr::new(
-        schema_path
-            .to_str()
-            .expect("Invalid UTF-8 characters in file name"),
-        Span::call_site(),
-    );
-
-    tokens.extend(quote! {
-        const _: &str = std::include_str!(#schema_path);
-    });
-}
-
 #[derive(Debug)]
 pub enum Error {
     SchemaParseError(graphql_parser::schema::ParseError),
@@ -162,6 +170,16 @@ pub struct CodeGenBuilder {
     schema: SchemaLocation,
     context_type: Option<syn::Type>,
     error_type: Option<syn::Type>,
+    schema_type_name: Option<syn::Ident>,
+    query_trails_module_name: Option<syn::Ident>,
+    visibility: Option<syn::Visibility>,
+    fields_trait_suffix: Option<String>,
+    field_method_prefix: Option<String>,
+    acronyms: Option<Acronyms>,
+    enum_derives: Option<Vec<syn::Path>>,
+    input_object_derives: Option<Vec<syn::Path>>,
+    scalar_derives: Option<Vec<syn::Path>>,
+    config: Config,
 }
 
 impl CodeGenBuilder {
@@ -175,11 +193,112 @@ impl CodeGenBuilder {
         self
     }
 
+    pub fn schema_type_name(mut self, schema_type_name: syn::Ident) -> Self {
+        self.schema_type_name = Some(schema_type_name);
+        self
+    }
+
+    pub fn query_trails_module_name(mut self, query_trails_module_name: syn::Ident) -> Self {
+        self.query_trails_module_name = Some(query_trails_module_name);
+        self
+    }
+
+    pub fn visibility(mut self, visibility: syn::Visibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    pub fn fields_trait_suffix(mut self, fields_trait_suffix: String) -> Self {
+        self.fields_trait_suffix = Some(fields_trait_suffix);
+        self
+    }
+
+    pub fn field_method_prefix(mut self, field_method_prefix: String) -> Self {
+        self.field_method_prefix = Some(field_method_prefix);
+        self
+    }
+
+    pub fn acronyms(mut self, acronyms: Acronyms) -> Self {
+        self.acronyms = Some(acronyms);
+        self
+    }
+
+    pub fn enum_derives(mut self, enum_derives: Vec<syn::Path>) -> Self {
+        self.enum_derives = Some(enum_derives);
+        self
+    }
+
+    pub fn input_object_derives(mut self, input_object_derives: Vec<syn::Path>) -> Self {
+        self.input_object_derives = Some(input_object_derives);
+        self
+    }
+
+    pub fn scalar_derives(mut self, scalar_derives: Vec<syn::Path>) -> Self {
+        self.scalar_derives = Some(scalar_derives);
+        self
+    }
+
+    /// Use settings from a config file for everything that isn't set on the builder.
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
     pub fn finish(self) -> CodeGen {
+        let config = self.config;
+
+        let mut naming = Naming::default();
+        if let Some(fields_trait_suffix) = self.fields_trait_suffix.or(config.fields_trait_suffix) {
+            naming.fields_trait_suffix = fields_trait_suffix;
+        }
+        if let Some(field_method_prefix) = self.field_method_prefix.or(config.field_method_prefix) {
+            naming.field_method_prefix = field_method_prefix;
+        }
+        if let Some(acronyms) = self.acronyms.or(config.acronyms) {
+            naming.acronyms = acronyms;
+        }
+
+        let derives = Derives {
+            enums: self
+                .enum_derives
+                .or(config.enum_derives)
+                .unwrap_or_default(),
+            input_objects: self
+                .input_object_derives
+                .or(config.input_object_derives)
+                .unwrap_or_default(),
+            scalars: self
+                .scalar_derives
+                .or(config.scalar_derives)
+                .unwrap_or_default(),
+        };
+
         CodeGen {
             schema: self.schema,
-            context_type: self.context_type.unwrap_or_else(default_context_type),
-            error_type: self.error_type.unwrap_or_else(default_error_type),
+            options: CodeGenOptions {
+                context_type: self
+                    .context_type
+                    .or(config.context_type)
+                    .unwrap_or_else(default_context_type),
+                error_type: self
+                    .error_type
+                    .or(config.error_type)
+                    .unwrap_or_else(default_error_type),
+                schema_type_name: self
+                    .schema_type_name
+                    .or(config.schema_type_name)
+                    .unwrap_or_else(default_schema_type_name),
+                query_trails_module_name: self
+                    .query_trails_module_name
+                    .or(config.query_trails_module_name)
+                    .unwrap_or_else(default_query_trails_module_name),
+                visibility: self
+                    .visibility
+                    .or(config.visibility)
+                    .unwrap_or_else(default_visibility),
+                naming,
+                derives,
+            },
         }
     }
 }
@@ -191,13 +310,26 @@ enum SchemaLocation {
 }
 
 pub fn default_error_type() -> syn::Type {
-    syn::parse_str("juniper::FieldError").expect("Failed to parse default error type")
+    syn::parse_str("juniper_from_schema::juniper::FieldError")
+        .expect("Failed to parse default error type")
 }
 
 pub fn default_context_type() -> syn::Type {
     syn::parse_str("Context").expect("Failed to parse default context type")
 }
 
+pub fn default_schema_type_name() -> syn::Ident {
+    syn::parse_str("Schema").expect("Failed to parse default schema type name")
+}
+
+pub fn default_query_trails_module_name() -> syn::Ident {
+    syn::parse_str("query_trails").expect("Failed to parse default query trails module name")
+}
+
+pub fn default_visibility() -> syn::Visibility {
+    syn::parse_str("pub").expect("Failed to parse default visibility")
+}
+
 fn debugging_enabled() -> bool {
     std::env::var("JUNIPER_FROM_SCHEMA_DEBUG")
         .map(|val| val == "1")
@@ -12,8 +12,11 @@
 
 mod parse_input;
 
-use juniper_from_schema_code_gen::CodeGen;
+use juniper_from_schema_code_gen::{CodeGen, Config};
 use parse_input::GraphqlSchemaFromFileInput;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use std::path::PathBuf;
 
 /// Read a GraphQL schema file and generate corresponding Juniper macro calls.
 ///
@@ -24,22 +27,64 @@ pub fn graphql_schema_from_file(input: proc_macro::TokenStream) -> proc_macro::T
         schema_path,
         context_type,
         error_type,
+        schema_type_name,
+        query_trails_module_name,
+        visibility,
+        fields_trait_suffix,
+        field_method_prefix,
+        acronyms,
+        enum_derives,
+        input_object_derives,
+        scalar_derives,
+        config_file,
     } = match syn::parse::<GraphqlSchemaFromFileInput>(input) {
         Ok(p) => p,
         Err(e) => return e.to_compile_error().into(),
     };
 
-    let mut builder = CodeGen::build_from_schema_file(schema_path);
+    let (config_path, config) = match load_config(config_file) {
+        Ok(config) => config,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let mut builder = CodeGen::build_from_schema_file(schema_path).config(config);
     if let Some(context_type) = context_type {
         builder = builder.context_type(context_type);
     }
     if let Some(error_type) = error_type {
         builder = builder.error_type(error_type);
     }
+    if let Some(schema_type_name) = schema_type_name {
+        builder = builder.schema_type_name(schema_type_name);
+    }
+    if let Some(query_trails_module_name) = query_trails_module_name {
+        builder = builder.query_trails_module_name(query_trails_module_name);
+    }
+    if let Some(visibility) = visibility {
+        builder = builder.visibility(visibility);
+    }
+    if let Some(fields_trait_suffix) = fields_trait_suffix {
+        builder = builder.fields_trait_suffix(fields_trait_suffix);
+    }
+    if let Some(field_method_prefix) = field_method_prefix {
+        builder = builder.field_method_prefix(field_method_prefix);
+    }
+    if let Some(acronyms) = acronyms {
+        builder = builder.acronyms(acronyms);
+    }
+    if let Some(enum_derives) = enum_derives {
+        builder = builder.enum_derives(enum_derives);
+    }
+    if let Some(input_object_derives) = input_object_derives {
+        builder = builder.input_object_derives(input_object_derives);
+    }
+    if let Some(scalar_derives) = scalar_derives {
+        builder = builder.scalar_derives(scalar_derives);
+    }
     let code_gen = builder.finish();
 
     match code_gen.generate_code() {
-        Ok(tokens) => tokens.into(),
+        Ok(tokens) => with_config_tracked(tokens, config_path).into(),
         Err(errors) => panic!("{}", errors),
     }
 }
@@ -79,10 +124,51 @@ pub fn graphql_schema_from_file(input: proc_macro::TokenStream) -> proc_macro::T
 pub fn graphql_schema(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let schema = input.to_string();
 
-    let code_gen = CodeGen::build_from_schema_literal(schema).finish();
+    let (config_path, config) = match load_config(None) {
+        Ok(config) => config,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let code_gen = CodeGen::build_from_schema_literal(schema)
+        .config(config)
+        .finish();
 
     match code_gen.generate_code() {
-        Ok(tokens) => tokens.into(),
+        Ok(tokens) => with_config_tracked(tokens, config_path).into(),
         Err(errors) => panic!("{}", errors),
     }
 }
+
+/// Load the config file given with `config_file`, or `juniper-from-schema.toml` next to
+/// `Cargo.toml` if there is one.
+fn load_config(config_file: Option<syn::LitStr>) -> syn::Result<(Option<PathBuf>, Config)> {
+    match config_file {
+        Some(lit) => {
+            let cargo_dir = std::env::var("CARGO_MANIFEST_DIR")
+                .expect("Env var `CARGO_MANIFEST_DIR` was missing");
+            let path = PathBuf::from(cargo_dir).join(lit.value());
+            let config = Config::from_file(&path)
+                .map_err(|err| syn::Error::new(lit.span(), err.to_string()))?;
+            Ok((Some(path), config))
+        }
+        None => match Config::from_manifest_dir() {
+            Ok(Some((path, config))) => Ok((Some(path), config)),
+            Ok(None) => Ok((None, Config::default())),
+            Err(err) => Err(syn::Error::new(Span::call_site(), err.to_string())),
+        },
+    }
+}
+
+/// Include the config file in the output so the code is regenerated when it changes.
+fn with_config_tracked(tokens: TokenStream, config_path: Option<PathBuf>) -> TokenStream {
+    match config_path {
+        Some(path) => {
+            let path = path.to_string_lossy();
+            quote! {
+                #tokens
+                const _: &str = std::include_str!(#path);
+            }
+        }
+        None => tokens,
+    }
+}
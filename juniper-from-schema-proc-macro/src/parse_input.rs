@@ -1,8 +1,9 @@
+use juniper_from_schema_code_gen::{parse_derives, validate_affix, Acronyms};
 use std::{fmt::Write, path::PathBuf};
 use syn::{
     self,
     parse::{Parse, ParseStream},
-    Ident, Token, Type,
+    Ident, LitStr, Token, Type, Visibility,
 };
 
 #[derive(Debug)]
@@ -10,6 +11,16 @@ pub struct GraphqlSchemaFromFileInput {
     pub schema_path: PathBuf,
     pub error_type: Option<Type>,
     pub context_type: Option<Type>,
+    pub schema_type_name: Option<Ident>,
+    pub query_trails_module_name: Option<Ident>,
+    pub visibility: Option<Visibility>,
+    pub fields_trait_suffix: Option<String>,
+    pub field_method_prefix: Option<String>,
+    pub acronyms: Option<Acronyms>,
+    pub enum_derives: Option<Vec<syn::Path>>,
+    pub input_object_derives: Option<Vec<syn::Path>>,
+    pub scalar_derives: Option<Vec<syn::Path>>,
+    pub config_file: Option<LitStr>,
 }
 
 impl Parse for GraphqlSchemaFromFileInput {
@@ -26,6 +37,16 @@ impl Parse for GraphqlSchemaFromFileInput {
 
         let mut error_type = None::<Type>;
         let mut context_type = None::<Type>;
+        let mut schema_type_name = None::<Ident>;
+        let mut query_trails_module_name = None::<Ident>;
+        let mut visibility = None::<Visibility>;
+        let mut fields_trait_suffix = None::<String>;
+        let mut field_method_prefix = None::<String>;
+        let mut acronyms = None::<Acronyms>;
+        let mut enum_derives = None::<Vec<syn::Path>>;
+        let mut input_object_derives = None::<Vec<syn::Path>>;
+        let mut scalar_derives = None::<Vec<syn::Path>>;
+        let mut config_file = None::<LitStr>;
 
         loop {
             if input.is_empty() {
@@ -42,10 +63,59 @@ impl Parse for GraphqlSchemaFromFileInput {
                     input.parse::<Token![:]>()?;
                     context_type = Some(input.parse()?);
                 }
+                "schema_type_name" => {
+                    input.parse::<Token![:]>()?;
+                    schema_type_name = Some(input.parse()?);
+                }
+                "query_trails_module_name" => {
+                    input.parse::<Token![:]>()?;
+                    query_trails_module_name = Some(input.parse()?);
+                }
+                "visibility" => {
+                    input.parse::<Token![:]>()?;
+                    visibility = Some(input.parse()?);
+                }
+                "fields_trait_suffix" => {
+                    input.parse::<Token![:]>()?;
+                    fields_trait_suffix = Some(parse_affix(input)?);
+                }
+                "field_method_prefix" => {
+                    input.parse::<Token![:]>()?;
+                    field_method_prefix = Some(parse_affix(input)?);
+                }
+                "acronyms" => {
+                    input.parse::<Token![:]>()?;
+                    let lit = input.parse::<LitStr>()?;
+                    let value = lit
+                        .value()
+                        .parse()
+                        .map_err(|err| syn::parse::Error::new(lit.span(), err))?;
+                    acronyms = Some(value);
+                }
+                "enum_derives" => {
+                    input.parse::<Token![:]>()?;
+                    enum_derives = Some(parse_derives_lit(input)?);
+                }
+                "input_object_derives" => {
+                    input.parse::<Token![:]>()?;
+                    input_object_derives = Some(parse_derives_lit(input)?);
+                }
+                "scalar_derives" => {
+                    input.parse::<Token![:]>()?;
+                    scalar_derives = Some(parse_derives_lit(input)?);
+                }
+                "config_file" => {
+                    input.parse::<Token![:]>()?;
+                    config_file = Some(input.parse()?);
+                }
                 other => {
                     let mut msg = String::new();
                     writeln!(msg, "Unknown `graphql_schema_from_file` config `{}`", other).unwrap();
-                    writeln!(msg, "Supported configs are `error_type` and `context_type`").unwrap();
+                    writeln!(
+                        msg,
+                        "Supported configs are `error_type`, `context_type`, `schema_type_name`, `query_trails_module_name`, `visibility`, `fields_trait_suffix`, `field_method_prefix`, `acronyms`, `enum_derives`, `input_object_derives`, `scalar_derives`, and `config_file`"
+                    )
+                    .unwrap();
                     return Err(syn::parse::Error::new(key.span(), msg));
                 }
             }
@@ -59,6 +129,28 @@ impl Parse for GraphqlSchemaFromFileInput {
             schema_path,
             error_type,
             context_type,
+            schema_type_name,
+            query_trails_module_name,
+            visibility,
+            fields_trait_suffix,
+            field_method_prefix,
+            acronyms,
+            enum_derives,
+            input_object_derives,
+            scalar_derives,
+            config_file,
         })
     }
 }
+
+fn parse_affix(input: ParseStream) -> syn::Result<String> {
+    let lit = input.parse::<LitStr>()?;
+    let value = lit.value();
+    validate_affix(&value).map_err(|err| syn::parse::Error::new(lit.span(), err))?;
+    Ok(value)
+}
+
+fn parse_derives_lit(input: ParseStream) -> syn::Result<Vec<syn::Path>> {
+    let lit = input.parse::<LitStr>()?;
+    parse_derives(&lit.value()).map_err(|err| syn::parse::Error::new(lit.span(), err))
+}
@@ -0,0 +1,97 @@
+//! Types used by the generated metadata for custom schema directives.
+
+/// A custom directive applied to a type, field, argument, or enum value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Directive {
+    /// The name of the directive, without the `@`.
+    pub name: &'static str,
+    /// The arguments passed to the directive. Arguments that were omitted but have a default
+    /// value in the directive definition are included with their default value.
+    pub arguments: &'static [(&'static str, DirectiveValue)],
+}
+
+impl Directive {
+    /// Find the argument with the given name.
+    pub fn argument(&self, name: &str) -> Option<&'static DirectiveValue> {
+        self.arguments
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Find the directive with the given name in a list of directives.
+    pub fn find(directives: &'static [Directive], name: &str) -> Option<&'static Directive> {
+        directives.iter().find(|directive| directive.name == name)
+    }
+}
+
+/// The value of an argument passed to a custom directive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DirectiveValue {
+    /// `null`
+    Null,
+    /// An `Int`.
+    Int(i64),
+    /// A `Float`.
+    Float(f64),
+    /// A `String`.
+    String(&'static str),
+    /// A `Boolean`.
+    Boolean(bool),
+    /// An enum value.
+    Enum(&'static str),
+    /// A list.
+    List(&'static [DirectiveValue]),
+    /// An input object.
+    Object(&'static [(&'static str, DirectiveValue)]),
+}
+
+/// The custom directives applied to a field and its arguments.
+///
+/// Also used for input object fields and enum values, which never have arguments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldDirectives {
+    /// The GraphQL name of the field.
+    pub name: &'static str,
+    /// The directives applied to the field itself.
+    pub directives: &'static [Directive],
+    /// The directives applied to the field's arguments. Arguments without custom directives are
+    /// omitted.
+    pub arguments: &'static [ArgumentDirectives],
+}
+
+impl FieldDirectives {
+    /// Find the directive with the given name applied to the field.
+    pub fn directive(&self, name: &str) -> Option<&'static Directive> {
+        Directive::find(self.directives, name)
+    }
+
+    /// Find the directives applied to the argument with the given name.
+    pub fn argument(&self, name: &str) -> Option<&'static ArgumentDirectives> {
+        self.arguments.iter().find(|arg| arg.name == name)
+    }
+
+    /// Find the field with the given GraphQL name in a list of fields.
+    pub fn find(
+        fields: &'static [FieldDirectives],
+        name: &str,
+    ) -> Option<&'static FieldDirectives> {
+        fields.iter().find(|field| field.name == name)
+    }
+}
+
+/// The custom directives applied to an argument.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArgumentDirectives {
+    /// The GraphQL name of the argument.
+    pub name: &'static str,
+    /// The directives applied to the argument.
+    pub directives: &'static [Directive],
+}
+
+impl ArgumentDirectives {
+    /// Find the directive with the given name applied to the argument.
+    pub fn directive(&self, name: &str) -> Option<&'static Directive> {
+        Directive::find(self.directives, name)
+    }
+}
@@ -20,19 +20,36 @@
 //!     - [Enumeration types](#enumeration-types)
 //!     - [Default argument values](#default-argument-values)
 //!     - [Subscriptions](#subscriptions)
+//!     - [Descriptions](#descriptions)
 //! - [Supported schema directives](#supported-schema-directives)
 //!     - [Definition for `@juniper`](#definition-for-juniper)
 //!     - [Customizing ownership](#customizing-ownership)
 //!     - [Infallible fields](#infallible-fields)
 //!     - [Async resolvers](#async-resolvers)
+//!     - [Defaults for types and the schema](#defaults-for-types-and-the-schema)
+//!     - [Argument structs](#argument-structs)
+//!     - [Leaving out the executor](#leaving-out-the-executor)
+//!     - [Types with lifetimes](#types-with-lifetimes)
+//!     - [Explicit nulls](#explicit-nulls)
+//!     - [Custom directives](#custom-directives)
 //! - [GraphQL to Rust types](#graphql-to-rust-types)
+//!     - [Customizing Rust names](#customizing-rust-names)
+//!     - [Using types defined elsewhere](#using-types-defined-elsewhere)
+//!     - [Mapping enums and input objects onto existing types](#mapping-enums-and-input-objects-onto-existing-types)
+//!     - [Derives](#derives)
 //! - [Query trails](#query-trails)
 //!     - [Abbreviated example](#abbreviated-example)
 //!     - [Types](#types)
 //!     - [Downcasting for interface and union `QueryTrail`s](#downcasting-for-interface-and-union-querytrails)
 //!     - [`QueryTrail`s for fields that take arguments](#querytrails-for-fields-that-take-arguments)
 //! - [Customizing the error type](#customizing-the-error-type)
+//!     - [Error types for specific fields](#error-types-for-specific-fields)
 //! - [Customizing the context type](#customizing-the-context-type)
+//! - [Multiple schemas in one module](#multiple-schemas-in-one-module)
+//! - [Customizing the visibility](#customizing-the-visibility)
+//! - [Config file](#config-file)
+//! - [Runtime schema information](#runtime-schema-information)
+//!     - [Checking the schema served by juniper](#checking-the-schema-served-by-juniper)
 //! - [Inspecting the generated code](#inspecting-the-generated-code)
 //! - [Generating code in "build.rs"](#generating-code-in-buildrs)
 //!
@@ -119,6 +136,10 @@
 //! }
 //! ```
 //!
+//! The `schema` block is optional. Without it the types named `Query`, `Mutation`, and
+//! `Subscription` are used as the root types, as specified by GraphQL. `Schema` uses
+//! `EmptyMutation` and `EmptySubscription` for the ones that don't exist.
+//!
 //! # Example web app
 //!
 //! You can find an example of how to use this library together with [Rocket] and [Diesel] to make
@@ -452,6 +473,24 @@
 //! }
 //! ```
 //!
+//! Input objects where every field is nullable implement `Default`, using the fields' default
+//! values if they have any. Input objects with required fields instead get a builder that takes the
+//! required fields as arguments, so leaving one out is a compile error, and has a setter for each
+//! nullable field:
+//!
+//! ```ignore
+//! // For `input CreatePost { title: String!, body: String, draft: Boolean }`
+//! let input = CreatePost::builder("Hello".to_string())
+//!     .draft(true)
+//!     .build();
+//!
+//! assert_eq!(input.body, None);
+//! ```
+//!
+//! The builder is called `CreatePostBuilder`. A nullable field called `build` can't be used with
+//! the builder so it has to be given another name with
+//! [`@juniper(rust_name: "...")`](#customizing-rust-names).
+//!
 //! ## Enumeration types
 //!
 //! GraphQL enumeration types will be converted into normal Rust enums. The name of each variant
@@ -524,7 +563,7 @@
 //! - `String`
 //! - `Boolean`
 //! - Enumerations
-//! - Input objects (as field arguments, see below)
+//! - Input objects
 //! - Lists containing some other supported type
 //!
 //! Abbreviated example (find [complete example here](https://github.com/davidpdrsn/juniper-from-schema/blob/master/examples/default_argument_values.rs)):
@@ -594,21 +633,28 @@
 //! }
 //! ```
 //!
-//! ### Input object gotchas
+//! ### Input object fields
 //!
-//! Defaults for input objects are only supported as field arguments. The following is not
-//! supported
+//! Fields of input objects can have default values as well:
 //!
 //! ```graphql
-//! input SomeType {
-//!   field: Int = 1
+//! input Pagination {
+//!   pageSize: Int = 20
+//!   cursor: ID
 //! }
 //! ```
 //!
-//! This isn't supported because [the spec is unclear about how to handle multiple nested
-//! defaults](https://github.com/webonyx/graphql-php/issues/350).
+//! As the spec requires, the default value is only used when the field is left out. Since it can
+//! still be set to `null`, the field remains an `Option<i32>`: `{}` gives `Some(20)` and
+//! `{ pageSize: null }` gives `None`. The default values of fields are also used when the field is
+//! left out of an argument's default value, so `pagination: Pagination = {}` gives a `pageSize` of
+//! `Some(20)`. The same goes for input objects read from [`QueryTrail`s for fields that take
+//! arguments](#querytrails-for-fields-that-take-arguments). The default values of fields show up
+//! in introspection as well.
+//!
+//! ### Input object gotchas
 //!
-//! Also, defaults are only used if no arguments are passed. So given the schema
+//! Defaults for arguments are only used if no arguments are passed. So given the schema
 //!
 //! ```graphql
 //! input Input {
@@ -916,12 +962,19 @@
 //! }
 //! ```
 //!
+//! ## Descriptions
+//!
+//! Descriptions in the schema are passed on to juniper, so they show up in introspection. They
+//! are also added as doc comments to the generated `*Fields` traits and their `field_*` methods,
+//! input objects, enums, and the query trail methods, together with deprecation reasons and
+//! default argument values. That way they show up in `cargo doc` and your editor.
+//!
 //! # Supported schema directives
 //!
 //! A number of [schema directives][] are supported that lets you customize the generated code:
 //!
-//! - `@juniper(ownership: "owned|borrowed|as_ref")`. For customizing ownership of returned data.
-//! More info [here](#customizing-ownership).
+//! - `@juniper(ownership: "owned|borrowed|as_ref|arc|str|cow|slice|iter")`. For customizing
+//! ownership of returned data. More info [here](#customizing-ownership).
 //! - `@juniper(infallible: true|false)`. Customize if a field should return `Result<T, _>` or
 //! just `T`. More info
 //! [here](http://localhost:4000/juniper_from_schema/index.html#infallible-fields).
@@ -929,8 +982,39 @@
 //! async. The default is sync. More info [here](#async-resolvers).
 //! - `@juniper(stream_item_infallible: true|false)`. For choosing whether the stream produces
 //! `Result`s or plain values. Default is `true` meaning the stream does not produce `Result`s.
+//! - `@juniper(rust_name: "...")`. For giving a type, field, argument, enum value, or input field
+//! a different name in Rust. More info [here](#customizing-rust-names).
+//! - `@juniper(error_type: "...")`. For using a different error type for some fields. More info
+//! [here](#error-types-for-specific-fields).
+//! - `@juniper(args_struct: true|false)`. For passing a field's arguments to the resolver in a
+//! single struct. More info [here](#argument-structs).
+//! - `@juniper(executor: true|false)`. For leaving the executor out of the resolver's signature.
+//! More info [here](#leaving-out-the-executor).
+//! - `@juniper(lifetime: true|false)`. For object types whose Rust struct has a lifetime
+//! parameter. More info [here](#types-with-lifetimes).
+//! - `@juniper(rust_path: "...")`. For using an object type's struct from another module. More
+//! info [here](#using-types-defined-elsewhere).
+//! - `@juniper(rust_type: "...")`. For using an existing Rust enum or struct for an enum or input
+//! object instead of generating one. More info
+//! [here](#mapping-enums-and-input-objects-onto-existing-types).
+//! - `@juniper(derive: "...")`. For deriving more traits on a generated enum, input object, or
+//! scalar. More info [here](#derives).
+//! - `@juniper(nullable_kind: "option"|"tristate")`. For telling an explicit `null` apart from a
+//! missing argument or input field. More info [here](#explicit-nulls).
 //! - `@deprecated`. For deprecating types in your schema. Also supports supplying a reason with
 //! `@deprecated(reason: "...")`
+//! - `@specifiedBy(url: "...")`. For linking a scalar to its specification. Supported on all
+//! scalars, including the special case scalars. juniper doesn't support exposing the URL through
+//! introspection so it is only added to the docs of the generated scalar type, if it has a
+//! description.
+//!
+//! `ownership`, `infallible`, `async`, `error_type`, `args_struct`, and `executor` can also be
+//! put on object types, interfaces, and the `schema` definition to change the default for all
+//! their fields. More info [here](#defaults-for-types-and-the-schema).
+//!
+//! `@deprecated` is also allowed on field arguments and input object fields, as long as they
+//! aren't required (non-null without a default value). juniper cannot expose those deprecations
+//! through introspection so they only show up in the generated docs.
 //!
 //! [schema directives]: https://www.apollographql.com/docs/apollo-server/schema/directives/
 //!
@@ -946,10 +1030,24 @@
 //!     with_time_zone: Boolean = true,
 //!     async: Boolean = false,
 //!     stream_item_infallible: Boolean = true,
-//!     stream_type: String = null
-//! ) on FIELD_DEFINITION | SCALAR
+//!     stream_type: String = null,
+//!     rust_name: String = null,
+//!     error_type: String = null,
+//!     args_struct: Boolean = false,
+//!     executor: Boolean = true,
+//!     lifetime: Boolean = false,
+//!     rust_path: String = null,
+//!     rust_type: String = null,
+//!     derive: String = null,
+//!     nullable_kind: String = null
+//! ) on FIELD_DEFINITION | SCALAR | SCHEMA | OBJECT | INTERFACE | UNION | ENUM | ENUM_VALUE
+//!     | INPUT_OBJECT | INPUT_FIELD_DEFINITION | ARGUMENT_DEFINITION
 //! ```
 //!
+//! `rust_name`, `error_type`, `args_struct`, `executor`, and the locations after `SCALAR` can be
+//! left out if you don't use
+//! them or [defaults](#defaults-for-types-and-the-schema).
+//!
 //! This directive definition is allowed in your schema, as well as any other directive definition.
 //! Definitions of `@juniper` that differ from this are not allowed though.
 //!
@@ -977,9 +1075,24 @@
 //! - `@juniper(ownership: "owned")`: The return type will be owned (`FieldResult<T>`).
 //! - `@juniper(ownership: "as_ref")`: Only applicable for `Option` and `Vec` return types. Changes
 //! the inner type to be borrowed (`FieldResult<Option<&T>>` or `FieldResult<Vec<&T>>`).
-//!
-//! Note that fields in subscription types must use `@juniper(ownership: "owned")`. `"as_ref"` or
-//! `"borrowed"` are not supported.
+//! - `@juniper(ownership: "arc")`: The values will be shared through an `Arc`
+//! (`FieldResult<Arc<T>>`). For `Option` and `Vec` return types the inner type is put in the `Arc`
+//! (`FieldResult<Vec<Arc<T>>>`). This is useful when your data already lives in `Arc`s, for
+//! example in a cache, and cloning it would be expensive.
+//! - `@juniper(ownership: "str")`: Only applicable for `String` fields. The return type will be
+//! `FieldResult<&str>`, so the string can be borrowed from a `Box<str>` or a string constant.
+//! - `@juniper(ownership: "cow")`: Only applicable for `String` fields. The return type will be
+//! `FieldResult<Cow<'_, str>>`. Borrowed values are copied into a `String` when the field is
+//! resolved.
+//! - `@juniper(ownership: "slice")`: Only applicable for list fields. The list will be borrowed
+//! as a slice (`FieldResult<&[T]>`).
+//! - `@juniper(ownership: "iter")`: Only applicable for list fields. The return type will be
+//! `FieldResult<impl IntoIterator<Item = T>>`, which avoids collecting into a `Vec` yourself.
+//! This uses `impl Trait` in trait methods and therefore requires Rust 1.75 or newer. It is not
+//! supported on async fields.
+//!
+//! Note that fields in subscription types must use `@juniper(ownership: "owned")` or
+//! `@juniper(ownership: "arc")`. `"as_ref"` or `"borrowed"` are not supported.
 //!
 //! Example:
 //!
@@ -1000,6 +1113,9 @@
 //!         borrowed: String!
 //!         owned: String! @juniper(ownership: "owned")
 //!         asRef: String @juniper(ownership: "as_ref")
+//!         shared: [String!]! @juniper(ownership: "arc")
+//!         name: String! @juniper(ownership: "str")
+//!         ids: [Int!]! @juniper(ownership: "slice")
 //!     }
 //! }
 //!
@@ -1020,6 +1136,21 @@
 //!         // ...
 //!         # unimplemented!()
 //!     }
+//!
+//!     fn field_shared(&self, _: &Executor<Context>) -> FieldResult<Vec<std::sync::Arc<String>>> {
+//!         // ...
+//!         # unimplemented!()
+//!     }
+//!
+//!     fn field_name(&self, _: &Executor<Context>) -> FieldResult<&str> {
+//!         // ...
+//!         # unimplemented!()
+//!     }
+//!
+//!     fn field_ids(&self, _: &Executor<Context>) -> FieldResult<&[i32]> {
+//!         // ...
+//!         # unimplemented!()
+//!     }
 //! }
 //! ```
 //!
@@ -1146,6 +1277,358 @@
 //! }
 //! ```
 //!
+//! ## Defaults for types and the schema
+//!
+//! Putting `ownership`, `infallible`, `async`, `error_type`, `args_struct`, or `executor` on every
+//! field gets repetitive.
+//! They can also be put on an object type or interface, in which case they become the default for
+//! all fields of that type, or on the `schema` definition, in which case they become the default
+//! for all fields in the schema. The most specific setting wins, so a field directive overrides its type, which
+//! overrides the schema.
+//!
+//! Example:
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper_from_schema::*;
+//! # use juniper::*;
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! # fn main() {}
+//! graphql_schema! {
+//!     schema @juniper(ownership: "owned") {
+//!         query: Query
+//!     }
+//!
+//!     type Query {
+//!         user: User!
+//!     }
+//!
+//!     type User @juniper(infallible: true) {
+//!         id: ID!
+//!         name: String! @juniper(ownership: "borrowed")
+//!     }
+//! }
+//!
+//! pub struct Query;
+//!
+//! impl QueryFields for Query {
+//!     fn field_user(&self, _: &Executor<Context>, _: &QueryTrail<'_, User, Walked>) -> FieldResult<User> {
+//!         // ...
+//!         # unimplemented!()
+//!     }
+//! }
+//!
+//! pub struct User {
+//!     id: ID,
+//!     name: String,
+//! }
+//!
+//! impl UserFields for User {
+//!     fn field_id(&self, _: &Executor<Context>) -> ID {
+//!         self.id.clone()
+//!     }
+//!
+//!     fn field_name(&self, _: &Executor<Context>) -> &String {
+//!         &self.name
+//!     }
+//! }
+//! ```
+//!
+//! Defaults on an interface apply to the interface's own fields. Types implementing the interface
+//! don't inherit them, but for the interface's fields they must end up with the same settings as
+//! the interface, otherwise you get a compile error. So a default on an interface usually has to
+//! be repeated on the types implementing it.
+//!
+//! ## Argument structs
+//!
+//! Every argument of a field normally becomes its own parameter on the `field_*` method. For
+//! fields with many arguments you can use `@juniper(args_struct: true)` to instead get a struct
+//! with a public field per argument, which is passed as a single `args` parameter. Default values
+//! are already applied. Adding an optional argument to the schema then only adds a field to the
+//! struct, rather than changing the signature of the method.
+//!
+//! The struct is named after the type and the field, so `allPosts` on `Query` gets a
+//! `QueryAllPostsArgs` struct. Fields without arguments don't get a struct.
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper_from_schema::*;
+//! # use juniper::*;
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! # fn main() {}
+//! graphql_schema! {
+//!     schema {
+//!         query: Query
+//!     }
+//!
+//!     type Query {
+//!         allPosts(
+//!             first: Int = 10,
+//!             authorId: ID,
+//!             published: Boolean
+//!         ): [String!]! @juniper(ownership: "owned", args_struct: true)
+//!     }
+//! }
+//!
+//! pub struct Query;
+//!
+//! impl QueryFields for Query {
+//!     fn field_all_posts(
+//!         &self,
+//!         _: &Executor<Context>,
+//!         args: QueryAllPostsArgs,
+//!     ) -> FieldResult<Vec<String>> {
+//!         let first: i32 = args.first;
+//!         let author_id: Option<ID> = args.author_id;
+//!         // ...
+//!         # unimplemented!()
+//!     }
+//! }
+//! ```
+//!
+//! Like other field settings `args_struct` can be enabled for a whole type or the schema, as
+//! described [above](#defaults-for-types-and-the-schema). The `*_args` method on the
+//! [`QueryTrail`](#querytrails-for-fields-that-take-arguments) of an object type returns the same
+//! struct. Types implementing an interface must use the same setting as the interface for the
+//! interface's fields, otherwise you get a compile error.
+//!
+//! ## Leaving out the executor
+//!
+//! Many resolvers, such as plain getters, never use the executor. `@juniper(executor: false)`
+//! removes the `executor` parameter from the `field_*` method. Fields returning a type still get
+//! the `QueryTrail`. It is mostly useful on a whole type:
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper_from_schema::*;
+//! # use juniper::*;
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! # fn main() {}
+//! graphql_schema! {
+//!     schema {
+//!         query: Query
+//!     }
+//!
+//!     type Query {
+//!         user: User! @juniper(ownership: "owned")
+//!     }
+//!
+//!     type User @juniper(executor: false) {
+//!         id: ID!
+//!         name: String!
+//!     }
+//! }
+//!
+//! pub struct Query;
+//!
+//! impl QueryFields for Query {
+//!     fn field_user(&self, _: &Executor<Context>, _: &QueryTrail<'_, User, Walked>) -> FieldResult<User> {
+//!         // ...
+//!         # unimplemented!()
+//!     }
+//! }
+//!
+//! pub struct User {
+//!     id: ID,
+//!     name: String,
+//! }
+//!
+//! impl UserFields for User {
+//!     fn field_id(&self) -> FieldResult<&ID> {
+//!         Ok(&self.id)
+//!     }
+//!
+//!     fn field_name(&self) -> FieldResult<&String> {
+//!         Ok(&self.name)
+//!     }
+//! }
+//! ```
+//!
+//! As with `args_struct`, types implementing an interface must use the same setting as the
+//! interface for the interface's fields, otherwise you get a compile error.
+//!
+//! ## Types with lifetimes
+//!
+//! Objects can be views that borrow from data loaded elsewhere, such as `UserView<'a>`
+//! borrowing from a batch of rows, rather than owning their data. Put
+//! `@juniper(lifetime: true)` on the type to generate code for a struct with one lifetime
+//! parameter:
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper_from_schema::*;
+//! # use juniper::*;
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! # fn main() {}
+//! graphql_schema! {
+//!     schema {
+//!         query: Query
+//!     }
+//!
+//!     type Query {
+//!         users: [User!]! @juniper(ownership: "owned")
+//!     }
+//!
+//!     type User @juniper(lifetime: true) {
+//!         name: String! @juniper(ownership: "str")
+//!     }
+//! }
+//!
+//! pub struct Query {
+//!     names: Vec<String>,
+//! }
+//!
+//! impl QueryFields for Query {
+//!     fn field_users(
+//!         &self,
+//!         _: &Executor<Context>,
+//!         _: &QueryTrail<'_, User<'static>, Walked>,
+//!     ) -> FieldResult<Vec<User<'_>>> {
+//!         Ok(self.names.iter().map(|name| User { name }).collect())
+//!     }
+//! }
+//!
+//! pub struct User<'a> {
+//!     name: &'a str,
+//! }
+//!
+//! impl<'a> UserFields for User<'a> {
+//!     fn field_name(&self, _: &Executor<Context>) -> FieldResult<&str> {
+//!         Ok(self.name)
+//!     }
+//! }
+//! ```
+//!
+//! Returned values borrow from `self`, so `User<'_>` above borrows from the `Query`.
+//!
+//! Unions with such a type as a member and interfaces implemented by such a type also get a
+//! lifetime parameter, for example `SearchResult<'a>`. Since the type of a `QueryTrail` is only
+//! used to know which methods it has, these types are used with `'static` in `QueryTrail`s, as in
+//! `QueryTrail<'_, User<'static>, Walked>`.
+//!
+//! The query, mutation, and subscription types cannot have lifetimes.
+//!
+//! ## Explicit nulls
+//!
+//! Nullable arguments and input fields become `Option<T>`, so passing `null` and leaving the value
+//! out both give `None`. That isn't enough for something like a patch, where `null` clears a value
+//! but leaving it out keeps it as it is. Put `@juniper(nullable_kind: "tristate")` on the argument
+//! or input field to get [`juniper::Nullable<T>`] instead:
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper_from_schema::*;
+//! # use juniper::*;
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! # fn main() {}
+//! graphql_schema! {
+//!     schema {
+//!         query: Query
+//!     }
+//!
+//!     type Query {
+//!         updateUser(input: UpdateUser!): Boolean!
+//!     }
+//!
+//!     input UpdateUser {
+//!         id: ID!
+//!         bio: String @juniper(nullable_kind: "tristate")
+//!     }
+//! }
+//!
+//! pub struct Query;
+//!
+//! impl QueryFields for Query {
+//!     fn field_update_user(
+//!         &self,
+//!         _: &Executor<Context>,
+//!         input: UpdateUser,
+//!     ) -> FieldResult<&bool> {
+//!         match input.bio {
+//!             Nullable::ImplicitNull => unimplemented!("leave the bio as it is"),
+//!             Nullable::ExplicitNull => unimplemented!("clear the bio"),
+//!             Nullable::Some(bio) => unimplemented!("set the bio"),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! `Nullable<T>` is also used for the argument in the resolver's signature and in the methods on
+//! [`QueryTrail`s for fields that take arguments](#querytrails-for-fields-that-take-arguments),
+//! which return `Nullable::ImplicitNull` for arguments left out of the query.
+//!
+//! `"option"` is the default. `"tristate"` is only allowed on nullable arguments and input fields
+//! without a default value, since a default value already fills in missing ones.
+//!
+//! [`juniper::Nullable<T>`]: https://docs.rs/juniper/latest/juniper/enum.Nullable.html
+//!
+//! ## Custom directives
+//!
+//! Directives other than `@juniper`, `@deprecated`, and `@specifiedBy` are allowed as long as
+//! the schema declares them. They don't affect the generated code, but their arguments are made
+//! available as constants so they can be inspected at runtime, for example to enforce
+//! authorization rules:
+//!
+//! - `{TYPE}_DIRECTIVES` holds the directives applied to the type itself.
+//! - `{TYPE}_FIELDS_DIRECTIVES` holds the directives applied to the fields of objects,
+//! interfaces, and input objects, as well as the directives applied to the fields' arguments.
+//! - `{TYPE}_VALUES_DIRECTIVES` holds the directives applied to the values of enums.
+//!
+//! Fields and enum values without any custom directives are left out. Arguments omitted where a
+//! directive is used are filled in from the defaults in the directive definition. The constants
+//! are only generated if the schema declares at least one directive.
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper_from_schema::*;
+//! # use juniper::*;
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! graphql_schema! {
+//!     directive @auth(requires: Role = ADMIN) on OBJECT | FIELD_DEFINITION
+//!
+//!     enum Role {
+//!         ADMIN
+//!         USER
+//!     }
+//!
+//!     type Query {
+//!         me: String! @auth(requires: USER) @juniper(ownership: "owned")
+//!     }
+//!
+//!     schema {
+//!         query: Query
+//!     }
+//! }
+//!
+//! pub struct Query;
+//!
+//! impl QueryFields for Query {
+//!     fn field_me(&self, _: &Executor<Context>) -> FieldResult<String> {
+//!         // ...
+//!         # unimplemented!()
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let me = FieldDirectives::find(QUERY_FIELDS_DIRECTIVES, "me").unwrap();
+//!     let auth = me.directive("auth").unwrap();
+//!
+//!     assert_eq!(auth.argument("requires"), Some(&DirectiveValue::Enum("USER")));
+//! }
+//! ```
+//!
 //! # GraphQL to Rust types
 //!
 //! This is how the standard GraphQL types will be mapped to Rust:
@@ -1156,6 +1639,160 @@
 //! - `Boolean` -> `bool`
 //! - `ID` -> [`juniper::ID`](https://docs.rs/juniper/latest/juniper/struct.ID.html)
 //!
+//! Fields, arguments, and input fields are converted to snake_case and enum values to CamelCase.
+//! Names that are Rust keywords, such as a `type` argument, become raw identifiers like `r#type`.
+//!
+//! Some names cannot be used. Two names that end up the same in Rust, such as the fields `userId`
+//! and `userID`, are an error, and so are names that turn into `self`, `Self`, `super`, or
+//! `crate`. Types must also not collide with the generated code, so `PostFields` cannot be
+//! declared alongside `Post`, `PostCommentsArgs` cannot be declared if `Post.comments` takes
+//! arguments, and arguments cannot be called `executor` or `trail`.
+//!
+//! ## Customizing Rust names
+//!
+//! `@juniper(rust_name: "...")` sets the Rust name of a type, field, argument, enum value, or input
+//! field. The name in the GraphQL schema served by juniper stays the same.
+//!
+//! ```graphql
+//! type Query {
+//!     userID(where: UserFilter @juniper(rust_name: "filter")): User @juniper(rust_name: "user")
+//! }
+//!
+//! type URL @juniper(rust_name: "Link") {
+//!     href: String!
+//! }
+//!
+//! enum Color {
+//!     RED @juniper(rust_name: "Crimson")
+//! }
+//! ```
+//!
+//! Here the resolver for `userID` is `QueryFields::field_user` with an argument called `filter`,
+//! the `URL` type is the struct `Link` with the trait `LinkFields`, and `RED` is `Color::Crimson`.
+//! The name given is used as is, without changing its case.
+//!
+//! Some naming conventions can also be changed for the whole schema:
+//!
+//! - `fields_trait_suffix`: The suffix of the traits with the fields of each object. Defaults to
+//! `"Fields"`.
+//! - `field_method_prefix`: The prefix of the trait methods that resolve fields. Defaults to
+//! `"field_"`. It can be empty.
+//! - `acronyms`: Either `"keep"`, which uses type names as they're written in the schema, or
+//! `"camel_case"`, which turns `URL` into `Url` and `HTTPRequest` into `HttpRequest`. Defaults to
+//! `"keep"`.
+//!
+//! ```ignore
+//! graphql_schema_from_file!(
+//!     "schema.graphql",
+//!     fields_trait_suffix: "Resolvers",
+//!     field_method_prefix: "resolve_",
+//!     acronyms: "camel_case",
+//! );
+//! ```
+//!
+//! `juniper_from_schema_build::CodeGen` has methods with the same names.
+//!
+//! ## Using types defined elsewhere
+//!
+//! By default the struct for an object type must be in scope where the code is generated and have
+//! the same name as the type. `@juniper(rust_path: "...")` uses a struct from another module
+//! instead:
+//!
+//! ```graphql
+//! type Account @juniper(rust_path: "crate::accounts::model::Account") {
+//!     id: ID!
+//! }
+//! ```
+//!
+//! The path is used wherever the generated code refers to the type, including union variants,
+//! interfaces, and `QueryTrail<'r, T, _>`. The path should start with `crate::` since query
+//! trails are generated in a module of their own. The names of the generated traits, such as
+//! `AccountFields`, are still based on the name of the type.
+//!
+//! `rust_path` only works for structs defined in the same crate as the schema. juniper implements
+//! its traits on the type with an inherent `impl` block, which Rust doesn't allow for types from
+//! other crates. To use a type from another crate, wrap it in a newtype and point `rust_path` at
+//! that.
+//!
+//! ## Mapping enums and input objects onto existing types
+//!
+//! Enums and input objects are normally generated. If you already have a Rust type for one, for
+//! example in your domain model, `@juniper(rust_type: "...")` uses that type instead:
+//!
+//! ```graphql
+//! enum Status @juniper(rust_type: "crate::domain::Status") {
+//!     ACTIVE
+//!     SUSPENDED @juniper(rust_name: "Banned")
+//! }
+//!
+//! input NewUser @juniper(rust_type: "crate::domain::NewUser") {
+//!     name: String!
+//!     status: Status!
+//! }
+//! ```
+//!
+//! ```
+//! mod domain {
+//!     #[derive(Clone, Debug)]
+//!     pub enum Status {
+//!         Active,
+//!         Banned,
+//!     }
+//!
+//!     pub struct NewUser {
+//!         pub name: String,
+//!         pub status: Status,
+//!     }
+//! }
+//! ```
+//!
+//! The enum must have a variant for each value and the struct a public field for each input field,
+//! named like the generated ones would be. `@juniper(rust_name: "...")` on values and fields picks
+//! a different name. The mapping is checked by generating hidden `GraphQLEnum` and
+//! `GraphQLInputObject` types, here `__Status` and `__NewUser`, and converting between those and
+//! your types, so a missing or misnamed variant or field is a compile error. juniper's traits
+//! are implemented on your types, so like with `rust_path` they must be defined in the same crate
+//! and the path should start with `crate::`. Query trail argument methods return your types.
+//!
+//! Since generated input objects derive `Clone` and `Debug`, your types must implement those too
+//! if they're used in the fields of input objects.
+//!
+//! ## Derives
+//!
+//! Generated enums derive `Debug`, `Eq`, `PartialEq`, `Ord`, `PartialOrd`, `Copy`, `Clone`, and
+//! `Hash`, input objects derive `Clone` and `Debug`, and custom scalars derive the same traits as
+//! enums except `Copy`. More derives can be added with `enum_derives`, `input_object_derives`,
+//! and `scalar_derives`, which take a comma separated list of paths:
+//!
+//! ```ignore
+//! graphql_schema_from_file!(
+//!     "schema.graphql",
+//!     input_object_derives: "serde::Serialize, serde::Deserialize",
+//!     enum_derives: "serde::Serialize, serde::Deserialize",
+//! );
+//! ```
+//!
+//! `@juniper(derive: "...")` on an enum, input object, or scalar replaces that list for the type:
+//!
+//! ```graphql
+//! enum Status @juniper(derive: "strum::EnumString, strum::Display") {
+//!     ACTIVE
+//! }
+//!
+//! input Pagination @juniper(derive: "std::default::Default") {
+//!     pageSize: Int
+//! }
+//! ```
+//!
+//! If `Serialize` or `Deserialize` from serde is derived, enum values and input fields are
+//! renamed to their GraphQL names with `#[serde(rename = "...")]`, so `pageSize` stays `pageSize`
+//! and `ACTIVE` stays `ACTIVE`. Scalars get `#[serde(transparent)]`. Types mapped onto existing
+//! types with `rust_type` don't get any derives since the type isn't generated.
+//!
+//! Input fields with `@juniper(nullable_kind: "tristate")` are `juniper::Nullable<T>`, which
+//! doesn't implement serde's traits. So input objects with such fields can't derive `Serialize` or
+//! `Deserialize`.
+//!
 //! # Query trails
 //!
 //! If you're not careful about preloading associations for deeply nested queries you risk getting
@@ -1596,6 +2233,9 @@
 //! The name of the arguments struct will always be `{name of type}{name of field}Args` (e.g.
 //! `CountryUsersArgs`). The method names will always be the name of the arguments in snake case.
 //!
+//! If the field uses an [argument struct](#argument-structs), `*_args` returns that struct
+//! instead, with every argument read from the query.
+//!
 //! The `*_args` method is only defined on `Walked` query trails so if you get an error like:
 //!
 //! ```text
@@ -1665,7 +2305,69 @@
 //! }
 //! ```
 //!
-//! [`graphql_schema!`] does not support changing the error type.
+//! [`graphql_schema!`] doesn't take an `error_type` argument, but the [config file](#config-file)
+//! works with both macros.
+//!
+//! ## Error types for specific fields
+//!
+//! Use `@juniper(error_type: "...")` to give some fields a different error type than the rest of
+//! the schema. Put it on an object type, interface, or the `schema` definition to change the
+//! default for all their fields, the same way as [other defaults](#defaults-for-types-and-the-schema).
+//! On subscription fields with `stream_item_infallible: false` it is also used for the items of
+//! the stream. Types implementing an interface must use the same error type as the interface for
+//! the interface's fields, otherwise you get a compile error.
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper::*;
+//! # use juniper_from_schema::*;
+//! # fn main() {}
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! graphql_schema! {
+//!     schema {
+//!         query: Query
+//!         mutation: Mutation
+//!     }
+//!
+//!     type Query {
+//!         ping: Boolean! @juniper(ownership: "owned")
+//!     }
+//!
+//!     type Mutation @juniper(error_type: "PaymentError", ownership: "owned") {
+//!         pay(amount: Int!): Boolean!
+//!     }
+//! }
+//!
+//! pub enum PaymentError {
+//!     InsufficientFunds,
+//! }
+//!
+//! impl juniper::IntoFieldError for PaymentError {
+//!     fn into_field_error(self) -> juniper::FieldError {
+//!         juniper::FieldError::from("Insufficient funds")
+//!     }
+//! }
+//!
+//! pub struct Query;
+//!
+//! impl QueryFields for Query {
+//!     fn field_ping(&self, _: &Executor<Context>) -> FieldResult<bool> {
+//!         Ok(true)
+//!     }
+//! }
+//!
+//! pub struct Mutation;
+//!
+//! impl MutationFields for Mutation {
+//!     fn field_pay(&self, _: &Executor<Context>, amount: i32) -> Result<bool, PaymentError> {
+//!         Err(PaymentError::InsufficientFunds)
+//!     }
+//! }
+//! ```
+//!
+//! The type is resolved where the generated code is, so use a path that works from there.
 //!
 //! [`graphql_schema!`]: macro.graphql_schema.html
 //! [`graphql_schema_from_file!`]: macro.graphql_schema_from_file.html
@@ -1715,6 +2417,141 @@
 //! [`graphql_schema!`]: macro.graphql_schema.html
 //! [`graphql_schema_from_file!`]: macro.graphql_schema_from_file.html
 //!
+//! # Multiple schemas in one module
+//!
+//! The generated code contains a type alias called `Schema`, a constant called `SCHEMA_INFO`, and
+//! a module called `query_trails`. If you want to use [`graphql_schema_from_file!`] more than once in the same module you have to
+//! rename those with `schema_type_name` and `query_trails_module_name`:
+//!
+//! ```ignore
+//! graphql_schema_from_file!("schemas/public.graphql");
+//!
+//! graphql_schema_from_file!(
+//!     "schemas/admin.graphql",
+//!     schema_type_name: AdminSchema,
+//!     query_trails_module_name: admin_query_trails
+//! );
+//! ```
+//!
+//! The constant is named after the type alias, so the admin schema's is called
+//! `ADMIN_SCHEMA_INFO`. The types defined in the two schemas must not have the same names. If they do, call
+//! [`graphql_schema_from_file!`] in separate modules instead.
+//!
+//! [`graphql_schema_from_file!`]: macro.graphql_schema_from_file.html
+//!
+//! # Customizing the visibility
+//!
+//! All generated types, traits, the `Schema` type alias, and the `query_trails` module are `pub`
+//! by default. You can change that by calling [`graphql_schema_from_file!`] with
+//! `visibility: pub(crate)` (or any other Rust visibility).
+//!
+//! ```ignore
+//! graphql_schema_from_file!("schemas/internal.graphql", visibility: pub(crate));
+//! ```
+//!
+//! The generated code only refers to things through absolute paths such as
+//! `std::option::Option` and `juniper_from_schema::juniper`, so it doesn't break if your module
+//! defines types named `Option`, `Result`, or `String`.
+//!
+//! [`graphql_schema_from_file!`]: macro.graphql_schema_from_file.html
+//!
+//! # Config file
+//!
+//! Settings can also be put in a file called `juniper-from-schema.toml` next to your
+//! `Cargo.toml`. It is read by [`graphql_schema_from_file!`], [`graphql_schema!`], and
+//! [juniper-from-schema-build], so they all generate code the same way. Every setting is optional:
+//!
+//! ```toml
+//! context_type = "crate::Context"
+//! error_type = "crate::MyError"
+//! schema_type_name = "Schema"
+//! query_trails_module_name = "query_trails"
+//! visibility = "pub(crate)"
+//! fields_trait_suffix = "Fields"
+//! field_method_prefix = "field_"
+//! acronyms = "keep"
+//! enum_derives = ""
+//! input_object_derives = "serde::Serialize, serde::Deserialize"
+//! scalar_derives = ""
+//! ```
+//!
+//! Settings given to the macro or the build crate directly take precedence over the file. Keys
+//! that aren't supported and values that don't parse are errors.
+//!
+//! Use `config_file` to read a different file, relative to `CARGO_MANIFEST_DIR`:
+//!
+//! ```ignore
+//! graphql_schema_from_file!("schema.graphql", config_file: "graphql/juniper-from-schema.toml");
+//! ```
+//!
+//! [`graphql_schema_from_file!`]: macro.graphql_schema_from_file.html
+//! [`graphql_schema!`]: macro.graphql_schema.html
+//! [juniper-from-schema-build]: https://crates.io/crates/juniper-from-schema-build
+//!
+//! # Runtime schema information
+//!
+//! A constant called `SCHEMA_INFO` describes the schema the code was generated from. It contains
+//! the GraphQL schema itself as a `&'static str`, and every declared type with its kind and
+//! description. Each field lists its GraphQL name, the name of the Rust method that resolves it,
+//! its arguments, and the `ownership`, `async`, and `infallible` settings from `@juniper`. This
+//! is useful for admin tooling and logging.
+//!
+//! ```ignore
+//! use juniper_from_schema::{Ownership, TypeKind};
+//!
+//! graphql_schema_from_file!("schema.graphql");
+//!
+//! let query = SCHEMA_INFO.type_info("Query").unwrap();
+//! assert_eq!(query.kind, TypeKind::Object);
+//!
+//! let field = query.field("allPosts").unwrap();
+//! assert_eq!(field.rust_name, "field_all_posts");
+//! assert_eq!(field.ownership, Ownership::Owned);
+//!
+//! println!("{}", SCHEMA_INFO.sdl);
+//! ```
+//!
+//! See [`SchemaInfo`] for all the details.
+//!
+//! [`SchemaInfo`]: struct.SchemaInfo.html
+//!
+//! ## Checking the schema served by juniper
+//!
+//! The schema juniper actually serves is built by juniper's own macros from the generated code.
+//! [`assert_schema_matches`] prints that schema, compares it with the source schema, and panics
+//! with a list of every difference. That makes it possible to guard the whole schema with a single
+//! test:
+//!
+//! ```ignore
+//! #[test]
+//! fn schema_matches_source() {
+//!     let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new());
+//!     juniper_from_schema::assert_schema_matches(&schema, SCHEMA_INFO.sdl);
+//! }
+//! ```
+//!
+//! Use [`diff_schema`] if you want to inspect the differences yourself.
+//!
+//! Default values are compared as well, but juniper doesn't know about the defaults of field
+//! arguments since juniper-from-schema applies those itself. So every default argument value in
+//! your schema is reported as a difference. To check everything else:
+//!
+//! ```ignore
+//! #[test]
+//! fn schema_matches_source() {
+//!     let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new());
+//!     let diff = juniper_from_schema::diff_schema(&schema, SCHEMA_INFO.sdl)
+//!         .without_default_values();
+//!     assert!(diff.is_empty(), "{}", diff);
+//! }
+//! ```
+//!
+//! The [special case scalars](#special-case-scalars) are named by the crates that define them. If
+//! juniper uses a different name than your schema, that is reported as a difference as well.
+//!
+//! [`assert_schema_matches`]: fn.assert_schema_matches.html
+//! [`diff_schema`]: fn.diff_schema.html
+//!
 //! # Inspecting the generated code
 //!
 //! If you wish to see exactly what code gets generated you can set the env var
@@ -1757,6 +2594,14 @@ pub use juniper;
 
 pub use juniper_from_schema_proc_macro::{graphql_schema, graphql_schema_from_file};
 
+mod directives;
+mod schema_diff;
+mod schema_info;
+
+pub use directives::{ArgumentDirectives, Directive, DirectiveValue, FieldDirectives};
+pub use schema_diff::{assert_schema_matches, diff_schema, SchemaDiff, SchemaDifference};
+pub use schema_info::{FieldInfo, InputValueInfo, Ownership, SchemaInfo, TypeInfo, TypeKind};
+
 /// A type used to parameterize `QueryTrail` to know that `walk` has been called.
 pub struct Walked;
 
@@ -1811,6 +2656,12 @@ impl<'r, T, K> QueryTrail<'r, T, K> {
 /// ```rust,ignore
 /// juniper_from_schema::include_schema!();
 /// ```
+///
+/// If the schema was given a name with `CodeGen::name` you must pass the same name:
+///
+/// ```rust,ignore
+/// juniper_from_schema::include_schema!("admin");
+/// ```
 #[macro_export]
 macro_rules! include_schema {
     () => {
@@ -1819,6 +2670,14 @@ macro_rules! include_schema {
             "/juniper_from_schema_graphql_schema.rs"
         ));
     };
+    ($name:literal) => {
+        std::include!(std::concat!(
+            std::env!("OUT_DIR"),
+            "/juniper_from_schema_graphql_schema_",
+            $name,
+            ".rs"
+        ));
+    };
 }
 
 #[cfg(test)]
@@ -0,0 +1,460 @@
+//! Comparing the schema juniper serves with the GraphQL schema the code was generated from.
+
+use graphql_parser::schema::{
+    parse_schema, Definition, Directive, Document, EnumValue, Field, InputValue, Type,
+    TypeDefinition, Value,
+};
+use juniper::{GraphQLType, RootNode, ScalarValue};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+/// Compare the schema juniper serves with the GraphQL schema it was generated from.
+///
+/// `sdl` is usually `SCHEMA_INFO.sdl`. Both schemas are parsed and compared type by type, so
+/// formatting and the order of definitions don't matter. Directive definitions and custom
+/// directives are ignored since juniper doesn't know about them, but deprecations are compared.
+///
+/// Types that can't be reached from the root types are ignored since juniper leaves them out.
+///
+/// Default values are compared as well. juniper-from-schema applies the defaults of field
+/// arguments itself rather than telling juniper about them, so those are always reported as
+/// missing, at paths like `Query.allPosts(first:).default`. Use
+/// [`SchemaDiff::without_default_values`] if you want to leave them out.
+///
+/// # Panics
+///
+/// Panics if either schema fails to parse.
+///
+/// [`SchemaDiff::without_default_values`]: struct.SchemaDiff.html#method.without_default_values
+pub fn diff_schema<'a, QueryT, MutationT, SubscriptionT, S>(
+    schema: &RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    sdl: &str,
+) -> SchemaDiff
+where
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S>,
+    SubscriptionT: GraphQLType<S>,
+    S: ScalarValue + 'a,
+{
+    let juniper_sdl = schema.as_schema_language();
+
+    let expected = parse_schema::<&str>(sdl)
+        .unwrap_or_else(|err| panic!("Failed to parse the source schema: {}", err));
+    let actual = parse_schema::<&str>(&juniper_sdl)
+        .unwrap_or_else(|err| panic!("Failed to parse the schema printed by juniper: {}", err));
+
+    let expected = flatten(&expected);
+    let actual = flatten(&actual);
+
+    let mut differences = Vec::new();
+
+    for (path, expected_value) in &expected {
+        match actual.get(path) {
+            Some(actual_value) if actual_value == expected_value => {}
+            actual_value => differences.push(SchemaDifference {
+                path: path.clone(),
+                expected: Some(expected_value.clone()),
+                actual: actual_value.cloned(),
+            }),
+        }
+    }
+
+    for (path, actual_value) in &actual {
+        if !expected.contains_key(path) {
+            differences.push(SchemaDifference {
+                path: path.clone(),
+                expected: None,
+                actual: Some(actual_value.clone()),
+            });
+        }
+    }
+
+    differences.sort_by(|a, b| a.path.cmp(&b.path));
+
+    SchemaDiff { differences }
+}
+
+/// Assert that the schema juniper serves matches the GraphQL schema it was generated from.
+///
+/// See [`diff_schema`] for how the schemas are compared.
+///
+/// # Panics
+///
+/// Panics with a description of every difference if the schemas don't match.
+///
+/// [`diff_schema`]: fn.diff_schema.html
+pub fn assert_schema_matches<'a, QueryT, MutationT, SubscriptionT, S>(
+    schema: &RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    sdl: &str,
+) where
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S>,
+    SubscriptionT: GraphQLType<S>,
+    S: ScalarValue + 'a,
+{
+    let diff = diff_schema(schema, sdl);
+    if !diff.is_empty() {
+        panic!(
+            "The schema doesn't match the GraphQL schema it was generated from\n\n{}",
+            diff
+        );
+    }
+}
+
+/// The differences between two schemas. Returned by [`diff_schema`].
+///
+/// [`diff_schema`]: fn.diff_schema.html
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SchemaDiff {
+    /// The differences, sorted by path.
+    pub differences: Vec<SchemaDifference>,
+}
+
+impl SchemaDiff {
+    /// Are the schemas the same?
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Leave out the differences in default values.
+    ///
+    /// Useful for schemas with default argument values, which juniper doesn't know about.
+    pub fn without_default_values(self) -> Self {
+        let differences = self
+            .differences
+            .into_iter()
+            .filter(|difference| !difference.is_default_value())
+            .collect();
+        SchemaDiff { differences }
+    }
+}
+
+impl fmt::Display for SchemaDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for difference in &self.differences {
+            writeln!(f, "{}", difference)?;
+        }
+        Ok(())
+    }
+}
+
+/// A single difference between two schemas.
+///
+/// The path identifies what is different, for example `Query` for the kind of a type,
+/// `Query.allPosts` for the type of a field, `Query.allPosts(first:)` for the type of an argument,
+/// `Query.allPosts(first:).default` for its default value, or `Query.allPosts.description` for the
+/// description of a field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaDifference {
+    /// What is different.
+    pub path: String,
+    /// The value in the source schema. `None` if it only exists in juniper's schema.
+    pub expected: Option<String>,
+    /// The value in juniper's schema. `None` if it only exists in the source schema.
+    pub actual: Option<String>,
+}
+
+impl fmt::Display for SchemaDifference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.expected, &self.actual) {
+            (Some(expected), Some(actual)) => write!(
+                f,
+                "{}: expected `{}`, got `{}`",
+                self.path, expected, actual
+            ),
+            (Some(expected), None) => write!(f, "{}: `{}` is missing", self.path, expected),
+            (None, Some(actual)) => write!(f, "{}: `{}` is unexpected", self.path, actual),
+            (None, None) => write!(f, "{}", self.path),
+        }
+    }
+}
+
+impl SchemaDifference {
+    /// Is this a difference in the default value of an argument or input field?
+    pub fn is_default_value(&self) -> bool {
+        self.path.ends_with(".default")
+    }
+}
+
+const BUILT_IN_SCALARS: &[&str] = &["Int", "Float", "String", "Boolean", "ID"];
+
+// Juniper uses these for `EmptyMutation` and `EmptySubscription` so they're never in the source
+// schema
+const JUNIPER_EMPTY_ROOT_TYPES: &[&str] = &["_EmptyMutation", "_EmptySubscription"];
+
+/// Turn a schema into a map from paths to values, which makes comparing schemas simple.
+fn flatten<'a>(doc: &Document<'a, &'a str>) -> BTreeMap<String, String> {
+    let mut out = BTreeMap::new();
+    let mut roots = BTreeMap::new();
+    let mut types = BTreeMap::new();
+
+    for def in &doc.definitions {
+        match def {
+            Definition::SchemaDefinition(schema) => {
+                let operations = vec![
+                    ("query", schema.query),
+                    ("mutation", schema.mutation),
+                    ("subscription", schema.subscription),
+                ];
+                for (operation, type_name) in operations {
+                    if let Some(type_name) = type_name {
+                        roots.insert(operation, type_name.to_string());
+                    }
+                }
+            }
+            Definition::TypeDefinition(type_def) => {
+                types.insert(type_definition_name(type_def), type_def);
+            }
+            Definition::TypeExtension(_) | Definition::DirectiveDefinition(_) => {}
+        }
+    }
+
+    // Without a schema definition the root types are found by name
+    if roots.is_empty() {
+        for (operation, type_name) in &[
+            ("query", "Query"),
+            ("mutation", "Mutation"),
+            ("subscription", "Subscription"),
+        ] {
+            if types.contains_key(*type_name) {
+                roots.insert(operation, type_name.to_string());
+            }
+        }
+    }
+
+    let reachable = reachable_types(&types, roots.values().map(|name| name.as_str()));
+    for (name, type_def) in &types {
+        if reachable.contains(name) {
+            flatten_type(type_def, &mut out);
+        }
+    }
+
+    for (operation, type_name) in roots {
+        if !JUNIPER_EMPTY_ROOT_TYPES.contains(&type_name.as_str()) {
+            out.insert(format!("schema.{}", operation), type_name);
+        }
+    }
+
+    out
+}
+
+fn type_definition_name<'a>(type_def: &TypeDefinition<'a, &'a str>) -> &'a str {
+    match type_def {
+        TypeDefinition::Scalar(def) => def.name,
+        TypeDefinition::Object(def) => def.name,
+        TypeDefinition::Interface(def) => def.name,
+        TypeDefinition::Union(def) => def.name,
+        TypeDefinition::Enum(def) => def.name,
+        TypeDefinition::InputObject(def) => def.name,
+    }
+}
+
+/// The names of the types reachable from `roots`, which are the types juniper includes in its
+/// schema. The objects implementing a reachable interface are included as well since juniper
+/// registers those with the interface.
+fn reachable_types<'a: 'b, 'b>(
+    types: &BTreeMap<&'a str, &TypeDefinition<'a, &'a str>>,
+    roots: impl Iterator<Item = &'b str>,
+) -> BTreeSet<&'a str> {
+    let mut reachable = BTreeSet::new();
+    let mut stack = roots.collect::<Vec<_>>();
+
+    while let Some(name) = stack.pop() {
+        let (name, type_def) = match types.get_key_value(name) {
+            Some((name, type_def)) => (*name, *type_def),
+            None => continue,
+        };
+        if !reachable.insert(name) {
+            continue;
+        }
+
+        match type_def {
+            TypeDefinition::Scalar(_) | TypeDefinition::Enum(_) => {}
+            TypeDefinition::Object(def) => {
+                stack.extend(&def.implements_interfaces);
+                push_field_types(&def.fields, &mut stack);
+            }
+            TypeDefinition::Interface(def) => {
+                push_field_types(&def.fields, &mut stack);
+                stack.extend(types.values().filter_map(|other| match other {
+                    TypeDefinition::Object(object)
+                        if object.implements_interfaces.contains(&name) =>
+                    {
+                        Some(object.name)
+                    }
+                    _ => None,
+                }));
+            }
+            TypeDefinition::Union(def) => stack.extend(&def.types),
+            TypeDefinition::InputObject(def) => {
+                stack.extend(def.fields.iter().map(|field| named_type(&field.value_type)));
+            }
+        }
+    }
+
+    reachable
+}
+
+fn push_field_types<'a: 'b, 'b>(fields: &[Field<'a, &'a str>], stack: &mut Vec<&'b str>) {
+    for field in fields {
+        stack.push(named_type(&field.field_type));
+        stack.extend(
+            field
+                .arguments
+                .iter()
+                .map(|arg| named_type(&arg.value_type)),
+        );
+    }
+}
+
+fn named_type<'a>(ty: &Type<'a, &'a str>) -> &'a str {
+    match ty {
+        Type::NamedType(name) => name,
+        Type::ListType(inner) | Type::NonNullType(inner) => named_type(inner),
+    }
+}
+
+fn flatten_type<'a>(type_def: &TypeDefinition<'a, &'a str>, out: &mut BTreeMap<String, String>) {
+    let (name, description) = match type_def {
+        TypeDefinition::Scalar(def) => (def.name, &def.description),
+        TypeDefinition::Object(def) => (def.name, &def.description),
+        TypeDefinition::Interface(def) => (def.name, &def.description),
+        TypeDefinition::Union(def) => (def.name, &def.description),
+        TypeDefinition::Enum(def) => (def.name, &def.description),
+        TypeDefinition::InputObject(def) => (def.name, &def.description),
+    };
+
+    if BUILT_IN_SCALARS.contains(&name)
+        || JUNIPER_EMPTY_ROOT_TYPES.contains(&name)
+        || name.starts_with("__")
+    {
+        return;
+    }
+
+    insert_description(name, description, out);
+
+    match type_def {
+        TypeDefinition::Scalar(_) => {
+            out.insert(name.to_string(), "scalar".to_string());
+        }
+        TypeDefinition::Object(def) => {
+            out.insert(name.to_string(), "type".to_string());
+            if !def.implements_interfaces.is_empty() {
+                out.insert(
+                    format!("{}.implements", name),
+                    sorted_list(&def.implements_interfaces),
+                );
+            }
+            flatten_fields(name, &def.fields, out);
+        }
+        TypeDefinition::Interface(def) => {
+            out.insert(name.to_string(), "interface".to_string());
+            flatten_fields(name, &def.fields, out);
+        }
+        TypeDefinition::Union(def) => {
+            out.insert(name.to_string(), "union".to_string());
+            out.insert(format!("{}.members", name), sorted_list(&def.types));
+        }
+        TypeDefinition::Enum(def) => {
+            out.insert(name.to_string(), "enum".to_string());
+            flatten_enum_values(name, &def.values, out);
+        }
+        TypeDefinition::InputObject(def) => {
+            out.insert(name.to_string(), "input".to_string());
+            for field in &def.fields {
+                flatten_input_value(format!("{}.{}", name, field.name), field, out);
+            }
+        }
+    }
+}
+
+fn flatten_fields<'a>(
+    type_name: &str,
+    fields: &[Field<'a, &'a str>],
+    out: &mut BTreeMap<String, String>,
+) {
+    for field in fields {
+        let path = format!("{}.{}", type_name, field.name);
+
+        for arg in &field.arguments {
+            flatten_input_value(format!("{}({}:)", path, arg.name), arg, out);
+        }
+
+        insert_description(&path, &field.description, out);
+        insert_deprecation(&path, &field.directives, out);
+        out.insert(path, field.field_type.to_string());
+    }
+}
+
+fn flatten_enum_values<'a>(
+    type_name: &str,
+    values: &[EnumValue<'a, &'a str>],
+    out: &mut BTreeMap<String, String>,
+) {
+    for value in values {
+        let path = format!("{}.{}", type_name, value.name);
+
+        insert_description(&path, &value.description, out);
+        insert_deprecation(&path, &value.directives, out);
+        out.insert(path, "value".to_string());
+    }
+}
+
+fn flatten_input_value<'a>(
+    path: String,
+    input_value: &InputValue<'a, &'a str>,
+    out: &mut BTreeMap<String, String>,
+) {
+    insert_description(&path, &input_value.description, out);
+    if let Some(default_value) = &input_value.default_value {
+        out.insert(format!("{}.default", path), default_value.to_string());
+    }
+    out.insert(path, input_value.value_type.to_string());
+}
+
+fn insert_description(
+    path: &str,
+    description: &Option<String>,
+    out: &mut BTreeMap<String, String>,
+) {
+    if let Some(description) = description {
+        out.insert(
+            format!("{}.description", path),
+            description.trim().to_string(),
+        );
+    }
+}
+
+fn insert_deprecation<'a>(
+    path: &str,
+    directives: &[Directive<'a, &'a str>],
+    out: &mut BTreeMap<String, String>,
+) {
+    let deprecated = directives
+        .iter()
+        .find(|directive| directive.name == "deprecated");
+
+    if let Some(deprecated) = deprecated {
+        // Fall back to the default reason from the spec so a missing reason and the default one
+        // are considered the same
+        let reason = deprecated
+            .arguments
+            .iter()
+            .find(|(key, _)| *key == "reason")
+            .and_then(|(_, value)| match value {
+                Value::String(reason) => Some(reason.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| "No longer supported".to_string());
+
+        out.insert(format!("{}.deprecated", path), reason);
+    }
+}
+
+fn sorted_list(names: &[&str]) -> String {
+    let mut names = names.to_vec();
+    names.sort_unstable();
+    names.join(", ")
+}
@@ -0,0 +1,128 @@
+//! Types used by the generated runtime view of the schema.
+
+/// The schema the code was generated from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SchemaInfo {
+    /// The GraphQL schema the code was generated from.
+    ///
+    /// For schemas written inline with `graphql_schema!` this is the schema as Rust saw it, so
+    /// whitespace and comments are not preserved.
+    pub sdl: &'static str,
+    /// The types declared in the schema, in the order they're declared in.
+    pub types: &'static [TypeInfo],
+}
+
+impl SchemaInfo {
+    /// Find the type with the given GraphQL name.
+    pub fn type_info(&self, name: &str) -> Option<&'static TypeInfo> {
+        self.types.iter().find(|type_info| type_info.name == name)
+    }
+}
+
+/// A type declared in the schema.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TypeInfo {
+    /// The GraphQL name of the type.
+    pub name: &'static str,
+    /// What kind of type it is.
+    pub kind: TypeKind,
+    /// The description of the type, if any.
+    pub description: Option<&'static str>,
+    /// The fields of objects and interfaces. Empty for other kinds of types.
+    pub fields: &'static [FieldInfo],
+    /// The fields of input objects. Empty for other kinds of types.
+    pub input_fields: &'static [InputValueInfo],
+}
+
+impl TypeInfo {
+    /// Find the field with the given GraphQL name.
+    pub fn field(&self, name: &str) -> Option<&'static FieldInfo> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Find the input field with the given GraphQL name.
+    pub fn input_field(&self, name: &str) -> Option<&'static InputValueInfo> {
+        self.input_fields.iter().find(|field| field.name == name)
+    }
+}
+
+/// The different kinds of types a schema can declare.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TypeKind {
+    /// A custom scalar.
+    Scalar,
+    /// An object type, including the query, mutation, and subscription types.
+    Object,
+    /// An interface.
+    Interface,
+    /// A union.
+    Union,
+    /// An enum.
+    Enum,
+    /// An input object.
+    InputObject,
+}
+
+/// A field on an object or interface.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldInfo {
+    /// The GraphQL name of the field.
+    pub name: &'static str,
+    /// The name of the trait method that resolves the field, for example `field_all_posts`.
+    pub rust_name: &'static str,
+    /// The description of the field, if any.
+    pub description: Option<&'static str>,
+    /// The GraphQL type of the field, for example `[Post!]!`.
+    pub field_type: &'static str,
+    /// The arguments the field takes.
+    pub arguments: &'static [InputValueInfo],
+    /// The `ownership` set with `@juniper`.
+    pub ownership: Ownership,
+    /// Whether the resolver is async, set with `@juniper(async: true)`.
+    pub is_async: bool,
+    /// Whether the resolver is infallible, set with `@juniper(infallible: true)`.
+    pub infallible: bool,
+}
+
+impl FieldInfo {
+    /// Find the argument with the given GraphQL name.
+    pub fn argument(&self, name: &str) -> Option<&'static InputValueInfo> {
+        self.arguments.iter().find(|arg| arg.name == name)
+    }
+}
+
+/// An argument of a field or a field of an input object.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputValueInfo {
+    /// The GraphQL name.
+    pub name: &'static str,
+    /// The name of the Rust argument or struct field.
+    pub rust_name: &'static str,
+    /// The description, if any.
+    pub description: Option<&'static str>,
+    /// The GraphQL type, for example `Int!`.
+    pub value_type: &'static str,
+    /// The default value written as GraphQL, if any.
+    pub default_value: Option<&'static str>,
+}
+
+/// How the value returned by a resolver is owned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ownership {
+    /// `@juniper(ownership: "borrowed")`, the default.
+    Borrowed,
+    /// `@juniper(ownership: "owned")`.
+    Owned,
+    /// `@juniper(ownership: "as_ref")`.
+    AsRef,
+    /// `@juniper(ownership: "arc")`.
+    Arc,
+    /// `@juniper(ownership: "str")`.
+    Str,
+    /// `@juniper(ownership: "cow")`.
+    Cow,
+    /// `@juniper(ownership: "slice")`.
+    Slice,
+    /// `@juniper(ownership: "iter")`.
+    Iter,
+}
@@ -0,0 +1,151 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use assert_json_diff::assert_json_include;
+use juniper::{Executor, FieldResult, Variables, ID};
+use juniper_from_schema::graphql_schema;
+use serde_json::{self, json, Value};
+
+graphql_schema! {
+    type Query {
+        allPosts(first: Int = 10, authorId: ID, published: Boolean): String!
+            @juniper(ownership: "owned", args_struct: true)
+
+        user: User! @juniper(ownership: "owned")
+    }
+
+    type User @juniper(args_struct: true) {
+        fromQueryTrail: String! @juniper(ownership: "owned")
+
+        posts(first: Int = 5, after: String): String! @juniper(ownership: "owned")
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_all_posts(
+        &self,
+        _: &Executor<Context>,
+        args: QueryAllPostsArgs,
+    ) -> FieldResult<String> {
+        Ok(format!(
+            "{}, {:?}, {:?}",
+            args.first,
+            args.author_id.as_deref(),
+            args.published,
+        ))
+    }
+
+    fn field_user(
+        &self,
+        _: &Executor<Context>,
+        trail: &QueryTrail<User, Walked>,
+    ) -> FieldResult<User> {
+        Ok(User {
+            from_query_trail: describe_posts_args(trail.posts_args()),
+        })
+    }
+}
+
+pub struct User {
+    from_query_trail: String,
+}
+
+impl UserFields for User {
+    fn field_from_query_trail(&self, _: &Executor<Context>) -> FieldResult<String> {
+        Ok(self.from_query_trail.clone())
+    }
+
+    fn field_posts(&self, _: &Executor<Context>, args: UserPostsArgs) -> FieldResult<String> {
+        Ok(describe_posts_args(args))
+    }
+}
+
+// Taking `UserPostsArgs` checks that the query trail returns the same struct as the resolver gets
+fn describe_posts_args(args: UserPostsArgs) -> String {
+    format!("{}, {:?}", args.first, args.after)
+}
+
+type Context = ();
+
+#[test]
+fn test_default_values_are_applied() {
+    let value = run_query(r#"query { allPosts }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({ "allPosts": "10, None, None" })
+    );
+
+    let value = run_query(r#"query { allPosts(first: 2, authorId: "1", published: true) }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({ "allPosts": "2, Some(\"1\"), Some(true)" })
+    );
+}
+
+#[test]
+fn test_query_trail_returns_the_same_args() {
+    let value = run_query(
+        r#"query {
+            user {
+                fromQueryTrail
+                posts
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "user": {
+                "fromQueryTrail": "5, None",
+                "posts": "5, None",
+            }
+        })
+    );
+
+    let value = run_query(
+        r#"query {
+            user {
+                fromQueryTrail
+                posts(first: 1, after: "cursor")
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "user": {
+                "fromQueryTrail": "1, Some(\"cursor\")",
+                "posts": "1, Some(\"cursor\")",
+            }
+        })
+    );
+}
+
+fn run_query(query: &str) -> Value {
+    let ctx = ();
+
+    let (res, _errors) = juniper::execute_sync(
+        query,
+        None,
+        &Schema::new(
+            Query,
+            juniper::EmptyMutation::new(),
+            juniper::EmptySubscription::new(),
+        ),
+        &Variables::new(),
+        &ctx,
+    )
+    .unwrap();
+
+    let json = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    println!("--- <json> -----------------");
+    println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    println!("--- </json> -----------------");
+    json
+}
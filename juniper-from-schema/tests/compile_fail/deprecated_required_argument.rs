@@ -0,0 +1,20 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+      users(first: Int! @deprecated): Int!
+    }
+
+    schema {
+      query: Query
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_users(&self, executor: &Executor<Context>, first: i32) -> FieldResult<&i32> {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,20 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        node: Node! @juniper(ownership: "owned")
+    }
+
+    interface Node @juniper(executor: false) {
+        name: String!
+    }
+
+    type User implements Node {
+        name: String!
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
@@ -0,0 +1,23 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        count: Int! @juniper(ownership: "str")
+        name: String! @juniper(ownership: "slice")
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_count(&self, _: &Executor<Context>) -> FieldResult<&i32> {
+        todo!()
+    }
+
+    fn field_name(&self, _: &Executor<Context>) -> FieldResult<&String> {
+        todo!()
+    }
+}
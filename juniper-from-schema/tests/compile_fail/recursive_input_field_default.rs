@@ -0,0 +1,22 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        ping: Boolean!
+    }
+
+    input Page {
+        next: Page = {}
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_ping(&self, _: &Executor<Context>) -> FieldResult<&bool> {
+        todo!()
+    }
+}
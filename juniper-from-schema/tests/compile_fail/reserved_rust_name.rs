@@ -0,0 +1,16 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        self: Int!
+    }
+
+    type Item @juniper(rust_name: "Self") {
+        id: ID!
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
@@ -0,0 +1,22 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+      users: Int! @tag(nme: "x")
+    }
+
+    schema {
+      query: Query
+    }
+
+    directive @tag(name: String!) on FIELD_DEFINITION
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_users(&self, executor: &Executor<Context>) -> FieldResult<&i32> {
+        unimplemented!()
+    }
+}
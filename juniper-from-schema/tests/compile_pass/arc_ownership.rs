@@ -0,0 +1,85 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+use std::sync::Arc;
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+        subscription: Subscription
+    }
+
+    type Query {
+        user: User! @juniper(ownership: "arc")
+        users: [User!]! @juniper(ownership: "arc", async: true)
+        maybeUsers: [User] @juniper(ownership: "arc")
+        name: String! @juniper(ownership: "arc")
+    }
+
+    type Subscription {
+        users: User! @juniper(ownership: "arc")
+    }
+
+    type User {
+        id: ID!
+    }
+}
+
+pub struct Query {
+    user: Arc<User>,
+}
+
+#[juniper_from_schema::juniper::async_trait]
+impl QueryFields for Query {
+    fn field_user(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<Arc<User>> {
+        Ok(Arc::clone(&self.user))
+    }
+
+    async fn field_users<'s, 'r, 'a>(
+        &'s self,
+        executor: &Executor<'r, 'a, Context>,
+        trail: &QueryTrail<'r, User, Walked>,
+    ) -> FieldResult<Vec<Arc<User>>> {
+        Ok(vec![Arc::clone(&self.user)])
+    }
+
+    fn field_maybe_users(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<Option<Vec<Option<Arc<User>>>>> {
+        Ok(None)
+    }
+
+    fn field_name(&self, executor: &Executor<Context>) -> FieldResult<Arc<String>> {
+        Ok(Arc::new(String::new()))
+    }
+}
+
+pub struct Subscription;
+
+impl SubscriptionFields for Subscription {
+    fn field_users(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<
+        Pin<Box<dyn juniper_from_schema::futures::Stream<Item = Arc<User>> + Send>>,
+    > {
+        unimplemented!()
+    }
+}
+
+pub struct User {
+    id: ID,
+}
+
+impl UserFields for User {
+    fn field_id(&self, executor: &Executor<Context>) -> FieldResult<&ID> {
+        Ok(&self.id)
+    }
+}
@@ -0,0 +1,80 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    interface Entity @juniper(args_struct: true) {
+        id(full: Boolean = false): ID!
+    }
+
+    type Query @juniper(args_struct: true, ownership: "owned") {
+        posts(first: Int = 10, after: String, order: Order!): [Post!]!
+        count(first: Int): Int! @juniper(args_struct: false)
+        ping: Boolean!
+    }
+
+    type Post implements Entity @juniper(args_struct: true) {
+        id(full: Boolean = false): ID!
+        title: String!
+    }
+
+    enum Order {
+        ASC
+        DESC
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_posts(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, Post, Walked>,
+        args: QueryPostsArgs,
+    ) -> FieldResult<Vec<Post>> {
+        let QueryPostsArgs {
+            first,
+            after,
+            order,
+        } = args;
+        let _: i32 = first;
+        let _: Option<String> = after;
+        let _: Order = order;
+        unimplemented!()
+    }
+
+    fn field_count(&self, executor: &Executor<Context>, first: Option<i32>) -> FieldResult<i32> {
+        unimplemented!()
+    }
+
+    fn field_ping(&self, executor: &Executor<Context>) -> FieldResult<bool> {
+        unimplemented!()
+    }
+}
+
+pub struct Post {
+    id: ID,
+    title: String,
+}
+
+impl PostFields for Post {
+    fn field_id(&self, executor: &Executor<Context>, args: PostIdArgs) -> FieldResult<&ID> {
+        let _: bool = args.full;
+        Ok(&self.id)
+    }
+
+    fn field_title(&self, executor: &Executor<Context>) -> FieldResult<&String> {
+        Ok(&self.title)
+    }
+}
+
+fn query_trail_args(trail: &QueryTrail<'_, Query, Walked>) {
+    let args: QueryPostsArgs = trail.posts_args();
+    let _: i32 = args.first;
+
+    let _: Option<i32> = trail.count_args().first();
+}
@@ -0,0 +1,78 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+use std::borrow::Cow;
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    interface Named {
+        name: String! @juniper(ownership: "cow")
+    }
+
+    type Query implements Named {
+        name: String! @juniper(ownership: "cow")
+        title: String @juniper(ownership: "str")
+        tags: [String!]! @juniper(ownership: "cow", infallible: true)
+        ids: [Int!] @juniper(ownership: "slice")
+        users: [User!]! @juniper(ownership: "iter")
+        nicknames: [String] @juniper(ownership: "str", async: true)
+    }
+
+    type User {
+        id: ID!
+    }
+}
+
+pub struct Query {
+    name: Box<str>,
+    ids: Vec<i32>,
+}
+
+#[juniper_from_schema::juniper::async_trait]
+impl QueryFields for Query {
+    fn field_name(&self, executor: &Executor<Context>) -> FieldResult<Cow<'_, str>> {
+        Ok(Cow::Borrowed(&self.name))
+    }
+
+    fn field_title(&self, executor: &Executor<Context>) -> FieldResult<Option<&str>> {
+        Ok(Some("title"))
+    }
+
+    fn field_tags(&self, executor: &Executor<Context>) -> Vec<Cow<'_, str>> {
+        vec![Cow::Owned(format!("{}-tag", self.name))]
+    }
+
+    fn field_ids(&self, executor: &Executor<Context>) -> FieldResult<Option<&[i32]>> {
+        Ok(Some(&self.ids))
+    }
+
+    fn field_users(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<impl IntoIterator<Item = User>> {
+        Ok(self.ids.iter().map(|id| User {
+            id: ID::new(id.to_string()),
+        }))
+    }
+
+    async fn field_nicknames<'s, 'r, 'a>(
+        &'s self,
+        executor: &Executor<'r, 'a, Context>,
+    ) -> FieldResult<Option<Vec<Option<&'s str>>>> {
+        Ok(None)
+    }
+}
+
+pub struct User {
+    id: ID,
+}
+
+impl UserFields for User {
+    fn field_id(&self, executor: &Executor<Context>) -> FieldResult<&ID> {
+        Ok(&self.id)
+    }
+}
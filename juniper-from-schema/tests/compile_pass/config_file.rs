@@ -0,0 +1,34 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema_from_file!(
+    "../../../juniper-from-schema/tests/schemas/naming_options.graphql",
+    config_file: "../../../juniper-from-schema/tests/configs/naming.toml",
+    fields_trait_suffix: "Fields",
+);
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn resolve_current_url(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<URL, Walked>,
+    ) -> FieldResult<&URL> {
+        unimplemented!()
+    }
+}
+
+pub struct URL {
+    href: String,
+}
+
+impl URLFields for URL {
+    fn resolve_href(&self, executor: &Executor<Context>) -> FieldResult<&String> {
+        Ok(&self.href)
+    }
+}
+
+fn schema() -> ConfiguredSchema {
+    ConfiguredSchema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
@@ -0,0 +1,46 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    directive @auth(requires: Role = ADMIN) on OBJECT | FIELD_DEFINITION
+    directive @cost(complexity: Int!) on FIELD_DEFINITION
+    directive @tag(name: String!) on SCALAR | ENUM | ENUM_VALUE | INPUT_OBJECT | INPUT_FIELD_DEFINITION | ARGUMENT_DEFINITION
+
+    enum Role @tag(name: "roles") {
+        ADMIN @tag(name: "admin")
+        USER
+    }
+
+    scalar Cursor @tag(name: "cursor")
+
+    input Filter @tag(name: "filter") {
+        text: String @tag(name: "text")
+    }
+
+    type Query @auth {
+        users(after: Cursor @tag(name: "pagination"), filter: Filter): Int!
+            @auth(requires: USER)
+            @cost(complexity: 10)
+            @juniper(ownership: "owned")
+        ping: Boolean! @juniper(ownership: "owned")
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_users(
+        &self,
+        _: &Executor<Context>,
+        after: Option<Cursor>,
+        filter: Option<Filter>,
+    ) -> FieldResult<i32> {
+        unimplemented!()
+    }
+
+    fn field_ping(&self, _: &Executor<Context>) -> FieldResult<bool> {
+        unimplemented!()
+    }
+}
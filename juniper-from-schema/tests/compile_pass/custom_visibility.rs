@@ -0,0 +1,39 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+mod admin {
+    use super::*;
+
+    juniper_from_schema::graphql_schema_from_file!(
+        "../../../juniper-from-schema/tests/schemas/admin_schema.graphql",
+        visibility: pub(super)
+    );
+}
+
+use admin::{AdminQueryFields, QueryTrail, UserFields, Walked};
+
+pub struct AdminQuery;
+
+impl AdminQueryFields for AdminQuery {
+    fn field_users(
+        &self,
+        _: &Executor<Context>,
+        _: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<Vec<User>> {
+        unimplemented!()
+    }
+}
+
+pub struct User {
+    id: ID,
+}
+
+impl UserFields for User {
+    fn field_id(&self, _: &Executor<Context>) -> FieldResult<&ID> {
+        Ok(&self.id)
+    }
+}
+
+fn schema() -> admin::Schema {
+    admin::Schema::new(AdminQuery, EmptyMutation::new(), EmptySubscription::new())
+}
@@ -0,0 +1,33 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        users(
+            first: Int = 10 @deprecated(reason: "Use `limit`")
+            limit: Int @deprecated
+            filter: UserFilter
+        ): Int!
+    }
+
+    input UserFilter {
+        name: String @deprecated(reason: "Use `fullName`")
+        fullName: String
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_users(
+        &self,
+        executor: &Executor<Context>,
+        first: i32,
+        limit: Option<i32>,
+        filter: Option<UserFilter>,
+    ) -> FieldResult<&i32> {
+        unimplemented!()
+    }
+}
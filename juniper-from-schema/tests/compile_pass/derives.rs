@@ -0,0 +1,37 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema_from_file!(
+    "../../../juniper-from-schema/tests/schemas/derives.graphql",
+    input_object_derives: "serde::Serialize, serde::Deserialize",
+    enum_derives: "serde::Serialize",
+);
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_users(
+        &self,
+        executor: &Executor<Context>,
+        filter: UserFilter,
+        sort: Option<SortOrder>,
+    ) -> FieldResult<Vec<String>> {
+        let json = serde_json::to_string(&filter)?;
+        let filter: UserFilter = serde_json::from_str(&json)?;
+        unimplemented!()
+    }
+
+    fn field_contact(&self, executor: &Executor<Context>, email: Email) -> FieldResult<Email> {
+        let json = serde_json::to_value(&email)?;
+        let email: Email = serde_json::from_value(json)?;
+        Ok(email)
+    }
+}
+
+fn pagination() -> Pagination {
+    Pagination::default()
+}
+
+fn sort_order() -> serde_json::Result<String> {
+    serde_json::to_string(&SortOrder::Asc)
+}
@@ -0,0 +1,82 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        ping: Boolean! @juniper(ownership: "owned")
+        balance: Int! @juniper(ownership: "owned", error_type: "BalanceError")
+    }
+
+    type Mutation @juniper(ownership: "owned", error_type: "PaymentError") {
+        pay(amount: Int!): Boolean!
+        refund(amount: Int!): Boolean! @juniper(error_type: "juniper::FieldError")
+    }
+
+    type Subscription {
+        payments: Int! @juniper(
+            ownership: "owned",
+            stream_item_infallible: false,
+            error_type: "PaymentError"
+        )
+    }
+
+    schema {
+        query: Query
+        mutation: Mutation
+        subscription: Subscription
+    }
+}
+
+pub struct BalanceError;
+
+impl juniper::IntoFieldError for BalanceError {
+    fn into_field_error(self) -> juniper::FieldError {
+        unimplemented!()
+    }
+}
+
+pub struct PaymentError;
+
+impl juniper::IntoFieldError for PaymentError {
+    fn into_field_error(self) -> juniper::FieldError {
+        unimplemented!()
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_ping(&self, executor: &Executor<Context>) -> FieldResult<bool> {
+        Ok(true)
+    }
+
+    fn field_balance(&self, executor: &Executor<Context>) -> Result<i32, BalanceError> {
+        Err(BalanceError)
+    }
+}
+
+pub struct Mutation;
+
+impl MutationFields for Mutation {
+    fn field_pay(&self, executor: &Executor<Context>, amount: i32) -> Result<bool, PaymentError> {
+        Err(PaymentError)
+    }
+
+    fn field_refund(&self, executor: &Executor<Context>, amount: i32) -> FieldResult<bool> {
+        Ok(true)
+    }
+}
+
+pub struct Subscription;
+
+impl SubscriptionFields for Subscription {
+    fn field_payments<'s, 'r, 'a>(
+        &'s self,
+        executor: &Executor<'r, 'a, Context>,
+    ) -> Result<
+        Pin<Box<dyn juniper_from_schema::futures::Stream<Item = Result<i32, PaymentError>> + Send>>,
+        PaymentError,
+    > {
+        Err(PaymentError)
+    }
+}
@@ -0,0 +1,52 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema @juniper(ownership: "owned") {
+        query: Query
+    }
+
+    type Query @juniper(async: true) {
+        user: User!
+        name: String! @juniper(async: false, ownership: "borrowed")
+    }
+
+    type User @juniper(infallible: true) {
+        id: ID!
+        name: String! @juniper(infallible: false)
+    }
+}
+
+pub struct Query {
+    name: String,
+}
+
+#[juniper_from_schema::juniper::async_trait]
+impl QueryFields for Query {
+    async fn field_user<'s, 'r, 'a>(
+        &'s self,
+        executor: &Executor<'r, 'a, Context>,
+        trail: &QueryTrail<'r, User, Walked>,
+    ) -> FieldResult<User> {
+        unimplemented!()
+    }
+
+    fn field_name(&self, executor: &Executor<Context>) -> FieldResult<&String> {
+        Ok(&self.name)
+    }
+}
+
+pub struct User {
+    id: ID,
+    name: String,
+}
+
+impl UserFields for User {
+    fn field_id(&self, executor: &Executor<Context>) -> ID {
+        self.id.clone()
+    }
+
+    fn field_name(&self, executor: &Executor<Context>) -> FieldResult<String> {
+        Ok(self.name.clone())
+    }
+}
@@ -0,0 +1,32 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        ping: Boolean! @juniper(ownership: "owned")
+    }
+
+    type Mutation {
+        noop: Boolean! @juniper(ownership: "owned")
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_ping(&self, _: &Executor<Context>) -> FieldResult<bool> {
+        unimplemented!()
+    }
+}
+
+pub struct Mutation;
+
+impl MutationFields for Mutation {
+    fn field_noop(&self, _: &Executor<Context>) -> FieldResult<bool> {
+        unimplemented!()
+    }
+}
+
+fn schema() -> Schema {
+    Schema::new(Query, Mutation, EmptySubscription::new())
+}
@@ -0,0 +1,55 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        createPost(input: CreatePost!, page: Pagination): Boolean!
+    }
+
+    input CreatePost {
+        title: String!
+        authorId: ID!
+        body: String
+        tags: [String!]
+        draft: Boolean
+    }
+
+    input Pagination {
+        first: Int
+        after: String
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_create_post(
+        &self,
+        executor: &Executor<Context>,
+        input: CreatePost,
+        page: Option<Pagination>,
+    ) -> FieldResult<&bool> {
+        unimplemented!()
+    }
+}
+
+fn builder() -> CreatePost {
+    CreatePost::builder("Hello".to_string(), ID::new("1"))
+        .body("Hello, World!".to_string())
+        .draft(true)
+        .build()
+}
+
+fn builder_without_optional_fields() -> CreatePost {
+    let builder: CreatePostBuilder = CreatePost::builder("Hello".to_string(), ID::new("1"));
+    builder.build()
+}
+
+fn default() -> Pagination {
+    Pagination {
+        first: Some(10),
+        ..Pagination::default()
+    }
+}
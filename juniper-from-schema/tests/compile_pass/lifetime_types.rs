@@ -0,0 +1,99 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    interface Named {
+        name: String!
+    }
+
+    union SearchResult = User | Post
+
+    type Query {
+        users: [User!]! @juniper(ownership: "owned")
+        search(query: String!): [SearchResult!]! @juniper(ownership: "owned")
+        named: [Named!]! @juniper(ownership: "owned")
+    }
+
+    type User implements Named @juniper(lifetime: true) {
+        name: String!
+        friends: [User!]! @juniper(ownership: "owned")
+    }
+
+    type Post {
+        title: String!
+    }
+}
+
+pub struct Row {
+    name: String,
+}
+
+pub struct Query {
+    rows: Vec<Row>,
+}
+
+impl QueryFields for Query {
+    fn field_users(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, User<'static>, Walked>,
+    ) -> FieldResult<Vec<User<'_>>> {
+        Ok(self.rows.iter().map(|row| User { row }).collect())
+    }
+
+    fn field_search(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, SearchResult<'static>, Walked>,
+        query: String,
+    ) -> FieldResult<Vec<SearchResult<'_>>> {
+        let users: QueryTrail<'_, User<'static>, Walked> = trail.downcast();
+        let _: bool = users.name();
+
+        Ok(self
+            .rows
+            .iter()
+            .map(|row| SearchResult::from(User { row }))
+            .collect())
+    }
+
+    fn field_named(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, Named<'static>, Walked>,
+    ) -> FieldResult<Vec<Named<'_>>> {
+        Ok(self.rows.iter().map(|row| Named::from(User { row })).collect())
+    }
+}
+
+pub struct User<'a> {
+    row: &'a Row,
+}
+
+impl<'a> UserFields for User<'a> {
+    fn field_name(&self, executor: &Executor<Context>) -> FieldResult<&String> {
+        Ok(&self.row.name)
+    }
+
+    fn field_friends(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, User<'static>, Walked>,
+    ) -> FieldResult<Vec<User<'_>>> {
+        Ok(vec![User { row: self.row }])
+    }
+}
+
+pub struct Post {
+    title: String,
+}
+
+impl PostFields for Post {
+    fn field_title(&self, executor: &Executor<Context>) -> FieldResult<&String> {
+        Ok(&self.title)
+    }
+}
@@ -0,0 +1,50 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema_from_file!(
+    "../../../juniper-from-schema/tests/schemas/customizing_context_name.graphql",
+    context_type: Context
+);
+
+juniper_from_schema::graphql_schema_from_file!(
+    "../../../juniper-from-schema/tests/schemas/admin_schema.graphql",
+    schema_type_name: AdminSchema,
+    query_trails_module_name: admin_query_trails
+);
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_foo(&self, _: &Executor<Context>) -> FieldResult<&String> {
+        unimplemented!()
+    }
+}
+
+pub struct AdminQuery;
+
+impl AdminQueryFields for AdminQuery {
+    fn field_users(
+        &self,
+        _: &Executor<Context>,
+        _: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<Vec<User>> {
+        unimplemented!()
+    }
+}
+
+pub struct User {
+    id: ID,
+}
+
+impl UserFields for User {
+    fn field_id(&self, _: &Executor<Context>) -> FieldResult<&ID> {
+        Ok(&self.id)
+    }
+}
+
+fn schemas() -> (Schema, AdminSchema) {
+    (
+        Schema::new(Query, EmptyMutation::new(), EmptySubscription::new()),
+        AdminSchema::new(AdminQuery, EmptyMutation::new(), EmptySubscription::new()),
+    )
+}
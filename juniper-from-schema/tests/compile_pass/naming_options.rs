@@ -0,0 +1,31 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema_from_file!(
+    "../../../juniper-from-schema/tests/schemas/naming_options.graphql",
+    fields_trait_suffix: "Resolvers",
+    field_method_prefix: "resolve_",
+    acronyms: "camel_case",
+);
+
+pub struct Query;
+
+impl QueryResolvers for Query {
+    fn resolve_current_url(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<Url, Walked>,
+    ) -> FieldResult<&Url> {
+        unimplemented!()
+    }
+}
+
+pub struct Url {
+    href: String,
+}
+
+impl UrlResolvers for Url {
+    fn resolve_href(&self, executor: &Executor<Context>) -> FieldResult<&String> {
+        Ok(&self.href)
+    }
+}
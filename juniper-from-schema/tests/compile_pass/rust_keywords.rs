@@ -0,0 +1,65 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        match(type: String!, fn: Int): Item! @juniper(ownership: "owned")
+        search(where: Where!): [Item!]! @juniper(ownership: "owned")
+    }
+
+    type Item {
+        id: ID!
+        mod: Int!
+    }
+
+    input Where {
+        type: String
+        async: Boolean
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_match(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<Item, Walked>,
+        r#type: String,
+        r#fn: Option<i32>,
+    ) -> FieldResult<Item> {
+        unimplemented!()
+    }
+
+    fn field_search(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<Item, Walked>,
+        r#where: Where,
+    ) -> FieldResult<Vec<Item>> {
+        let _ = (r#where.r#type, r#where.r#async);
+        unimplemented!()
+    }
+}
+
+pub struct Item;
+
+impl ItemFields for Item {
+    fn field_id(&self, executor: &Executor<Context>) -> FieldResult<&ID> {
+        unimplemented!()
+    }
+
+    fn field_mod(&self, executor: &Executor<Context>) -> FieldResult<&i32> {
+        unimplemented!()
+    }
+}
+
+fn inspect_trail(trail: &QueryTrail<Query, Walked>) {
+    if let Some(item) = trail.r#match().walk() {
+        let _ = item.r#mod();
+    }
+    let args = trail.match_args();
+    let _ = (args.r#type(), args.r#fn());
+}
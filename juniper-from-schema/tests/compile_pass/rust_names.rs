@@ -0,0 +1,59 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        userID(where: UserFilter @juniper(rust_name: "filter")): URL @juniper(rust_name: "user", ownership: "owned")
+        color: Color! @juniper(ownership: "owned")
+    }
+
+    type URL @juniper(rust_name: "Link") {
+        href: String!
+    }
+
+    input UserFilter {
+        nameContains: String @juniper(rust_name: "name")
+    }
+
+    enum Color {
+        RED @juniper(rust_name: "Crimson")
+        GREEN
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_user(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<Link, Walked>,
+        filter: Option<UserFilter>,
+    ) -> FieldResult<Option<Link>> {
+        let _ = filter.map(|filter| filter.name);
+        unimplemented!()
+    }
+
+    fn field_color(&self, executor: &Executor<Context>) -> FieldResult<Color> {
+        Ok(Color::Crimson)
+    }
+}
+
+pub struct Link {
+    href: String,
+}
+
+impl LinkFields for Link {
+    fn field_href(&self, executor: &Executor<Context>) -> FieldResult<&String> {
+        Ok(&self.href)
+    }
+}
+
+fn inspect_trail(trail: &QueryTrail<Query, Walked>) {
+    if let Some(link) = trail.user().walk() {
+        let _ = link.href();
+    }
+    let _ = trail.user_args().filter();
+}
@@ -0,0 +1,61 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+mod accounts {
+    pub mod model {
+        pub struct Account {
+            pub id: juniper_from_schema::juniper::ID,
+        }
+    }
+}
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    interface Entity {
+        id: ID!
+    }
+
+    union SearchResult = Account
+
+    type Query {
+        account: Account! @juniper(ownership: "owned")
+        search: [SearchResult!]! @juniper(ownership: "owned")
+    }
+
+    type Account implements Entity @juniper(rust_path: "crate::accounts::model::Account") {
+        id: ID!
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_account(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, accounts::model::Account, Walked>,
+    ) -> FieldResult<accounts::model::Account> {
+        let _: bool = trail.id();
+        unimplemented!()
+    }
+
+    fn field_search(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, SearchResult, Walked>,
+    ) -> FieldResult<Vec<SearchResult>> {
+        let account = accounts::model::Account {
+            id: ID::new("1"),
+        };
+        Ok(vec![SearchResult::Account(account)])
+    }
+}
+
+impl AccountFields for accounts::model::Account {
+    fn field_id(&self, executor: &Executor<Context>) -> FieldResult<&ID> {
+        Ok(&self.id)
+    }
+}
@@ -0,0 +1,94 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+mod domain {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Status {
+        Active,
+        Banned,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct NewUser {
+        pub name: String,
+        pub status: Option<Status>,
+    }
+}
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    type Query {
+        status(user: NewUser!): Status! @juniper(ownership: "owned")
+        statuses(only: Status): [Status!]! @juniper(ownership: "owned")
+        user: User!
+    }
+
+    type User {
+        id: ID!
+        friends(status: Status!, filter: NewUser): [User!]!
+    }
+
+    enum Status @juniper(rust_type: "crate::domain::Status") {
+        ACTIVE
+        SUSPENDED @juniper(rust_name: "Banned")
+    }
+
+    input NewUser @juniper(rust_type: "crate::domain::NewUser") {
+        name: String!
+        status: Status
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_status(
+        &self,
+        executor: &Executor<Context>,
+        user: domain::NewUser,
+    ) -> FieldResult<domain::Status> {
+        Ok(user.status.unwrap_or(domain::Status::Active))
+    }
+
+    fn field_statuses(
+        &self,
+        executor: &Executor<Context>,
+        only: Option<domain::Status>,
+    ) -> FieldResult<Vec<domain::Status>> {
+        Ok(vec![domain::Status::Active, domain::Status::Banned])
+    }
+
+    fn field_user(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<&User> {
+        let args = trail.friends_args();
+        let status: domain::Status = args.status();
+        let filter: Option<domain::NewUser> = args.filter();
+        unimplemented!()
+    }
+}
+
+pub struct User {
+    id: ID,
+}
+
+impl UserFields for User {
+    fn field_id(&self, executor: &Executor<Context>) -> FieldResult<&ID> {
+        Ok(&self.id)
+    }
+
+    fn field_friends(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, User, Walked>,
+        status: domain::Status,
+        filter: Option<domain::NewUser>,
+    ) -> FieldResult<&Vec<User>> {
+        unimplemented!()
+    }
+}
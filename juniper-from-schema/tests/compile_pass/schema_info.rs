@@ -0,0 +1,46 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    "A blog post"
+    type Post {
+        id: ID!
+        title: String! @juniper(ownership: "owned", infallible: true)
+    }
+
+    input PostFilter {
+        title: String
+    }
+
+    type Query {
+        allPosts(first: Int = 10, filter: PostFilter): [Post!]! @juniper(ownership: "owned")
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_all_posts(
+        &self,
+        _: &Executor<Context>,
+        _: &QueryTrail<'_, Post, Walked>,
+        first: i32,
+        filter: Option<PostFilter>,
+    ) -> FieldResult<Vec<Post>> {
+        unimplemented!()
+    }
+}
+
+pub struct Post;
+
+impl PostFields for Post {
+    fn field_id(&self, _: &Executor<Context>) -> FieldResult<&ID> {
+        unimplemented!()
+    }
+
+    fn field_title(&self, _: &Executor<Context>) -> String {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,40 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+use chrono::prelude::*;
+use url::Url;
+
+juniper_from_schema::graphql_schema! {
+    "An opaque pagination cursor"
+    scalar Cursor @specifiedBy(url: "https://relay.dev/graphql/connections.htm")
+
+    scalar Url @specifiedBy(url: "https://tools.ietf.org/html/rfc3986")
+
+    scalar DateTimeUtc
+        @juniper(with_time_zone: true)
+        @specifiedBy(url: "https://tools.ietf.org/html/rfc3339")
+
+    type Query {
+        cursor: Cursor!
+        url: Url! @juniper(ownership: "owned")
+        now: DateTimeUtc! @juniper(ownership: "owned")
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_cursor(&self, _: &Executor<Context>) -> FieldResult<&Cursor> {
+        unimplemented!()
+    }
+
+    fn field_url(&self, _: &Executor<Context>) -> FieldResult<Url> {
+        unimplemented!()
+    }
+
+    fn field_now(&self, _: &Executor<Context>) -> FieldResult<DateTime<Utc>> {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,85 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        result(id: ID!): Result! @juniper(ownership: "owned")
+        option: Option @juniper(ownership: "owned")
+        boxes(filter: BoxFilter): [Box!]! @juniper(ownership: "owned")
+        search: [SearchResult!]! @juniper(ownership: "owned")
+    }
+
+    type Result {
+        ok: Boolean!
+    }
+
+    enum Option {
+        SOME
+        NONE
+    }
+
+    type Box {
+        size: Int!
+    }
+
+    input BoxFilter {
+        size: Int = 1
+    }
+
+    union SearchResult = Result | Box
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_result(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<Result, Walked>,
+        id: ID,
+    ) -> FieldResult<Result> {
+        unimplemented!()
+    }
+
+    fn field_option(
+        &self,
+        executor: &Executor<Context>,
+    ) -> FieldResult<std::option::Option<Option>> {
+        unimplemented!()
+    }
+
+    fn field_boxes(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<Box, Walked>,
+        filter: std::option::Option<BoxFilter>,
+    ) -> FieldResult<Vec<Box>> {
+        unimplemented!()
+    }
+
+    fn field_search(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<SearchResult, Walked>,
+    ) -> FieldResult<Vec<SearchResult>> {
+        unimplemented!()
+    }
+}
+
+pub struct Result;
+
+impl ResultFields for Result {
+    fn field_ok(&self, executor: &Executor<Context>) -> FieldResult<&bool> {
+        unimplemented!()
+    }
+}
+
+pub struct Box;
+
+impl BoxFields for Box {
+    fn field_size(&self, executor: &Executor<Context>) -> FieldResult<&i32> {
+        unimplemented!()
+    }
+}
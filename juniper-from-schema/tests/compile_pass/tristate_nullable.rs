@@ -0,0 +1,88 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        user: User!
+        updateUser(
+            input: UpdateUser!,
+            nickname: String @juniper(nullable_kind: "tristate")
+        ): Boolean!
+    }
+
+    type User {
+        id: ID!
+        posts(
+            before: String @juniper(nullable_kind: "tristate"),
+            first: Int @juniper(nullable_kind: "option")
+        ): [User!]!
+    }
+
+    input UpdateUser {
+        id: ID!
+        bio: String @juniper(nullable_kind: "tristate")
+        age: Int
+    }
+
+    input UserPatch {
+        bio: String @juniper(nullable_kind: "tristate")
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_user(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<&User> {
+        let args = trail.posts_args();
+        let before: juniper::Nullable<String> = args.before();
+        let first: Option<i32> = args.first();
+        unimplemented!()
+    }
+
+    fn field_update_user(
+        &self,
+        executor: &Executor<Context>,
+        input: UpdateUser,
+        nickname: juniper::Nullable<String>,
+    ) -> FieldResult<&bool> {
+        let bio: juniper::Nullable<String> = input.bio;
+        let age: Option<i32> = input.age;
+        unimplemented!()
+    }
+}
+
+pub struct User {
+    id: ID,
+}
+
+impl UserFields for User {
+    fn field_id(&self, executor: &Executor<Context>) -> FieldResult<&ID> {
+        Ok(&self.id)
+    }
+
+    fn field_posts(
+        &self,
+        executor: &Executor<Context>,
+        trail: &QueryTrail<'_, User, Walked>,
+        before: juniper::Nullable<String>,
+        first: Option<i32>,
+    ) -> FieldResult<&Vec<User>> {
+        unimplemented!()
+    }
+}
+
+fn builder() -> UpdateUser {
+    UpdateUser::builder(ID::new("1"))
+        .bio(juniper::Nullable::ExplicitNull)
+        .build()
+}
+
+fn default() -> UserPatch {
+    UserPatch::default()
+}
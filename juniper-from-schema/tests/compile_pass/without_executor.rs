@@ -0,0 +1,46 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema @juniper(executor: false) {
+        query: Query
+    }
+
+    type Query {
+        user(id: ID!): User! @juniper(ownership: "owned")
+        count: Int! @juniper(ownership: "owned", executor: true)
+    }
+
+    type User @juniper(async: true) {
+        id: ID!
+        name: String! @juniper(async: false)
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_user(&self, trail: &QueryTrail<'_, User, Walked>, id: ID) -> FieldResult<User> {
+        unimplemented!()
+    }
+
+    fn field_count(&self, executor: &Executor<Context>) -> FieldResult<i32> {
+        unimplemented!()
+    }
+}
+
+pub struct User {
+    id: ID,
+    name: String,
+}
+
+#[juniper_from_schema::juniper::async_trait]
+impl UserFields for User {
+    async fn field_id<'s, 'r>(&'s self) -> FieldResult<&'s ID> {
+        Ok(&self.id)
+    }
+
+    fn field_name(&self) -> FieldResult<&String> {
+        Ok(&self.name)
+    }
+}
@@ -0,0 +1,122 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use juniper::{Executor, FieldResult};
+use juniper_from_schema::{graphql_schema, Directive, DirectiveValue, FieldDirectives};
+
+graphql_schema! {
+    directive @auth(requires: Role = ADMIN) on OBJECT | FIELD_DEFINITION
+    directive @cost(complexity: Int!) on FIELD_DEFINITION
+    directive @tag(name: String!) on SCALAR | ENUM | ENUM_VALUE | INPUT_OBJECT | INPUT_FIELD_DEFINITION | ARGUMENT_DEFINITION
+
+    enum Role @tag(name: "roles") {
+        ADMIN @tag(name: "admin")
+        USER
+    }
+
+    scalar Cursor @tag(name: "cursor")
+
+    input Filter @tag(name: "filter") {
+        text: String @tag(name: "text")
+    }
+
+    type Query @auth {
+        users(after: Cursor @tag(name: "pagination"), filter: Filter): Int!
+            @auth(requires: USER)
+            @cost(complexity: 10)
+            @juniper(ownership: "owned")
+        ping: Boolean! @juniper(ownership: "owned")
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_users(
+        &self,
+        _: &Executor<Context>,
+        after: Option<Cursor>,
+        filter: Option<Filter>,
+    ) -> FieldResult<i32> {
+        unimplemented!()
+    }
+
+    fn field_ping(&self, _: &Executor<Context>) -> FieldResult<bool> {
+        unimplemented!()
+    }
+}
+
+type Context = ();
+
+#[test]
+fn test_type_directives() {
+    let auth = Directive::find(QUERY_DIRECTIVES, "auth").unwrap();
+    assert_eq!(
+        auth.argument("requires"),
+        Some(&DirectiveValue::Enum("ADMIN"))
+    );
+
+    assert_eq!(
+        Directive::find(ROLE_DIRECTIVES, "tag")
+            .unwrap()
+            .argument("name"),
+        Some(&DirectiveValue::String("roles")),
+    );
+    assert_eq!(
+        Directive::find(CURSOR_DIRECTIVES, "tag")
+            .unwrap()
+            .argument("name"),
+        Some(&DirectiveValue::String("cursor")),
+    );
+    assert_eq!(
+        Directive::find(FILTER_DIRECTIVES, "tag")
+            .unwrap()
+            .argument("name"),
+        Some(&DirectiveValue::String("filter")),
+    );
+}
+
+#[test]
+fn test_field_directives() {
+    let users = FieldDirectives::find(QUERY_FIELDS_DIRECTIVES, "users").unwrap();
+    assert_eq!(
+        users.directive("auth").unwrap().argument("requires"),
+        Some(&DirectiveValue::Enum("USER")),
+    );
+    assert_eq!(
+        users.directive("cost").unwrap().argument("complexity"),
+        Some(&DirectiveValue::Int(10)),
+    );
+    assert_eq!(
+        users
+            .argument("after")
+            .unwrap()
+            .directive("tag")
+            .unwrap()
+            .argument("name"),
+        Some(&DirectiveValue::String("pagination")),
+    );
+    assert!(users.argument("filter").is_none());
+    assert!(FieldDirectives::find(QUERY_FIELDS_DIRECTIVES, "ping").is_none());
+}
+
+#[test]
+fn test_enum_value_and_input_field_directives() {
+    assert_eq!(ROLE_VALUES_DIRECTIVES.len(), 1);
+    let admin = FieldDirectives::find(ROLE_VALUES_DIRECTIVES, "ADMIN").unwrap();
+    assert_eq!(
+        admin.directive("tag").unwrap().argument("name"),
+        Some(&DirectiveValue::String("admin")),
+    );
+
+    assert_eq!(FILTER_FIELDS_DIRECTIVES.len(), 1);
+    let text = FieldDirectives::find(FILTER_FIELDS_DIRECTIVES, "text").unwrap();
+    assert_eq!(
+        text.directive("tag").unwrap().argument("name"),
+        Some(&DirectiveValue::String("text")),
+    );
+}
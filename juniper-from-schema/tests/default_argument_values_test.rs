@@ -33,6 +33,10 @@ graphql_schema! {
         objectNullablePartial(arg: A = { a: "a arg" }): [String]! @juniper(ownership: "owned")
 
         objectNullableNesting(arg: B = { c: { x: 1 } }): [Int]! @juniper(ownership: "owned")
+
+        objectFieldDefault(arg: Page = {}): Int @juniper(ownership: "owned")
+
+        objectFieldDefaultRequired(arg: Page!): Int @juniper(ownership: "owned")
     }
 
     input CoordinateIn {
@@ -62,6 +66,10 @@ graphql_schema! {
         x: Int
     }
 
+    input Page {
+        size: Int = 20
+    }
+
     enum Unit { METER FOOT }
 
     schema { query: Query }
@@ -137,6 +145,22 @@ impl QueryFields for Query {
     ) -> FieldResult<Vec<Option<i32>>> {
         Ok(vec![b.c.and_then(|c| c.x)])
     }
+
+    fn field_object_field_default(
+        &self,
+        _: &Executor<Context>,
+        arg: Page,
+    ) -> FieldResult<Option<i32>> {
+        Ok(arg.size)
+    }
+
+    fn field_object_field_default_required(
+        &self,
+        _: &Executor<Context>,
+        arg: Page,
+    ) -> FieldResult<Option<i32>> {
+        Ok(arg.size)
+    }
 }
 
 pub struct CoordinateOut {
@@ -286,6 +310,52 @@ fn test_object_nesting() {
     );
 }
 
+#[test]
+fn test_object_field_default() {
+    let value = run_query(r#"query { objectFieldDefault }"#);
+    assert_json_include!(actual: value, expected: json!({ "objectFieldDefault": 20 }));
+
+    let value = run_query(r#"query { objectFieldDefault(arg: { size: 5 }) }"#);
+    assert_json_include!(actual: value, expected: json!({ "objectFieldDefault": 5 }));
+
+    let value = run_query(r#"query { objectFieldDefaultRequired(arg: {}) }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({ "objectFieldDefaultRequired": 20 })
+    );
+
+    // The default is only used if the field is left out
+    let value = run_query(r#"query { objectFieldDefaultRequired(arg: { size: null }) }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({ "objectFieldDefaultRequired": null })
+    );
+}
+
+#[test]
+fn test_object_field_default_introspection() {
+    let value = run_query(
+        r#"query {
+            __type(name: "Page") {
+                inputFields {
+                    name
+                    defaultValue
+                }
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "__type": {
+                "inputFields": [
+                    { "name": "size", "defaultValue": "20" },
+                ]
+            }
+        })
+    );
+}
+
 fn run_query(query: &str) -> Value {
     let ctx = ();
 
@@ -0,0 +1,101 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use assert_json_diff::assert_json_include;
+use juniper::{Executor, FieldResult, Variables};
+use juniper_from_schema::graphql_schema_from_file;
+use serde_json::{self, json, Value};
+
+graphql_schema_from_file!(
+    "tests/schemas/derives.graphql",
+    input_object_derives: "serde::Serialize, serde::Deserialize",
+    enum_derives: "serde::Serialize",
+);
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_users(
+        &self,
+        _: &Executor<Context>,
+        filter: UserFilter,
+        sort: Option<SortOrder>,
+    ) -> FieldResult<Vec<String>> {
+        Ok(vec![
+            serde_json::to_string(&filter)?,
+            serde_json::to_string(&sort)?,
+        ])
+    }
+
+    fn field_contact(&self, _: &Executor<Context>, email: Email) -> FieldResult<Email> {
+        Ok(email)
+    }
+}
+
+type Context = ();
+
+#[test]
+fn test_serialized_keys_are_graphql_names() {
+    let filter = UserFilter {
+        user_name: Some("bob".to_string()),
+        status: Some(Status::InActive),
+    };
+    assert_eq!(
+        serde_json::to_value(&filter).unwrap(),
+        json!({ "userName": "bob", "status": "IN_ACTIVE" })
+    );
+
+    assert_eq!(serde_json::to_value(&SortOrder::Asc).unwrap(), json!("ASC"));
+    assert_eq!(
+        serde_json::to_value(&Email("bob@example.com".to_string())).unwrap(),
+        json!("bob@example.com")
+    );
+}
+
+#[test]
+fn test_deserializing_graphql_names() {
+    let filter: UserFilter =
+        serde_json::from_value(json!({ "userName": "bob", "status": "IN_ACTIVE" })).unwrap();
+    assert_eq!(filter.user_name, Some("bob".to_string()));
+    assert_eq!(filter.status, Some(Status::InActive));
+
+    let email: Email = serde_json::from_value(json!("bob@example.com")).unwrap();
+    assert_eq!(email, Email("bob@example.com".to_string()));
+}
+
+#[test]
+fn test_serializing_arguments() {
+    let value =
+        run_query(r#"query { users(filter: { userName: "bob", status: IN_ACTIVE }, sort: DESC) }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "users": [r#"{"userName":"bob","status":"IN_ACTIVE"}"#, r#""DESC""#]
+        })
+    );
+}
+
+fn run_query(query: &str) -> Value {
+    let ctx = ();
+
+    let (res, _errors) = juniper::execute_sync(
+        query,
+        None,
+        &Schema::new(
+            Query,
+            juniper::EmptyMutation::new(),
+            juniper::EmptySubscription::new(),
+        ),
+        &Variables::new(),
+        &ctx,
+    )
+    .unwrap();
+
+    let json = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    println!("--- <json> -----------------");
+    println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    println!("--- </json> -----------------");
+    json
+}
@@ -0,0 +1,212 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use assert_json_diff::assert_json_include;
+use juniper::{Executor, FieldResult, Variables};
+use juniper_from_schema::graphql_schema;
+use serde_json::{self, json, Value};
+
+graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    interface Named {
+        name: String!
+    }
+
+    union SearchResult = User | Post
+
+    type Query {
+        users: [User!]! @juniper(ownership: "owned")
+        search(query: String!): [SearchResult!]! @juniper(ownership: "owned")
+        named: [Named!]! @juniper(ownership: "owned")
+    }
+
+    type User implements Named @juniper(lifetime: true) {
+        name: String!
+        friends: [User!]! @juniper(ownership: "owned")
+    }
+
+    type Post {
+        title: String!
+    }
+}
+
+pub struct Row {
+    name: String,
+}
+
+pub struct Query {
+    rows: Vec<Row>,
+    posts: Vec<Post>,
+}
+
+impl QueryFields for Query {
+    fn field_users(
+        &self,
+        _: &Executor<Context>,
+        _: &QueryTrail<'_, User<'static>, Walked>,
+    ) -> FieldResult<Vec<User<'_>>> {
+        Ok(self.rows.iter().map(|row| User { row }).collect())
+    }
+
+    fn field_search(
+        &self,
+        _: &Executor<Context>,
+        _: &QueryTrail<'_, SearchResult<'static>, Walked>,
+        query: String,
+    ) -> FieldResult<Vec<SearchResult<'_>>> {
+        let users = self
+            .rows
+            .iter()
+            .filter(|row| row.name.contains(&query))
+            .map(|row| SearchResult::from(User { row }));
+        let posts = self
+            .posts
+            .iter()
+            .filter(|post| post.title.contains(&query))
+            .map(|post| SearchResult::from(post.clone()));
+        Ok(users.chain(posts).collect())
+    }
+
+    fn field_named(
+        &self,
+        _: &Executor<Context>,
+        _: &QueryTrail<'_, Named<'static>, Walked>,
+    ) -> FieldResult<Vec<Named<'_>>> {
+        Ok(self
+            .rows
+            .iter()
+            .map(|row| Named::from(User { row }))
+            .collect())
+    }
+}
+
+pub struct User<'a> {
+    row: &'a Row,
+}
+
+impl<'a> UserFields for User<'a> {
+    fn field_name(&self, _: &Executor<Context>) -> FieldResult<&String> {
+        Ok(&self.row.name)
+    }
+
+    fn field_friends(
+        &self,
+        _: &Executor<Context>,
+        _: &QueryTrail<'_, User<'static>, Walked>,
+    ) -> FieldResult<Vec<User<'_>>> {
+        Ok(vec![User { row: self.row }])
+    }
+}
+
+#[derive(Clone)]
+pub struct Post {
+    title: String,
+}
+
+impl PostFields for Post {
+    fn field_title(&self, _: &Executor<Context>) -> FieldResult<&String> {
+        Ok(&self.title)
+    }
+}
+
+type Context = ();
+
+#[test]
+fn test_querying_object_with_lifetime() {
+    let value = run_query(r#"query { users { name friends { name } } }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "users": [
+                { "name": "Alice", "friends": [{ "name": "Alice" }] },
+                { "name": "Bob", "friends": [{ "name": "Bob" }] },
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_querying_union_member_with_lifetime() {
+    let value = run_query(
+        r#"query {
+            search(query: "A") {
+                __typename
+                ... on User { name }
+                ... on Post { title }
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "search": [
+                { "__typename": "User", "name": "Alice" },
+                { "__typename": "Post", "title": "A post" },
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_querying_interface_implementor_with_lifetime() {
+    let value = run_query(
+        r#"query {
+            named {
+                __typename
+                name
+                ... on User { friends { name } }
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "named": [
+                { "__typename": "User", "name": "Alice", "friends": [{ "name": "Alice" }] },
+                { "__typename": "User", "name": "Bob", "friends": [{ "name": "Bob" }] },
+            ]
+        })
+    );
+}
+
+fn run_query(query: &str) -> Value {
+    let ctx = ();
+
+    let query_root = Query {
+        rows: vec![
+            Row {
+                name: "Alice".to_string(),
+            },
+            Row {
+                name: "Bob".to_string(),
+            },
+        ],
+        posts: vec![Post {
+            title: "A post".to_string(),
+        }],
+    };
+
+    let (res, _errors) = juniper::execute_sync(
+        query,
+        None,
+        &Schema::new(
+            query_root,
+            juniper::EmptyMutation::new(),
+            juniper::EmptySubscription::new(),
+        ),
+        &Variables::new(),
+        &ctx,
+    )
+    .unwrap();
+
+    let json = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    println!("--- <json> -----------------");
+    println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    println!("--- </json> -----------------");
+    json
+}
@@ -62,6 +62,7 @@ graphql_schema! {
 
     input InputObject {
         value: String!
+        defaultField: Int = 7
     }
 
     enum Color {
@@ -100,6 +101,7 @@ impl QueryFields for Query {
                 "baz".to_string(),
                 c.field_with_arg_args().object_arg().value
             );
+            assert_eq!(Some(7), c.field_with_arg_args().object_arg().default_field);
             assert_eq!(
                 Cursor("cursor-value".to_string()),
                 c.field_with_arg_args().cursor_arg()
@@ -0,0 +1,119 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use assert_json_diff::assert_json_include;
+use juniper::{Executor, FieldResult, Variables};
+use juniper_from_schema::{assert_schema_matches, graphql_schema_from_file};
+use serde_json::{self, json, Value};
+
+graphql_schema_from_file!("tests/schemas/rust_names.graphql", acronyms: "camel_case");
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_user(
+        &self,
+        _: &Executor<Context>,
+        _: &QueryTrail<Link, Walked>,
+        filter: Option<UserFilter>,
+    ) -> FieldResult<Option<Link>> {
+        Ok(filter
+            .and_then(|filter| filter.name)
+            .map(|href| Link { href }))
+    }
+
+    fn field_color(&self, _: &Executor<Context>) -> FieldResult<Color> {
+        Ok(Color::Crimson)
+    }
+
+    fn field_last_http_request(
+        &self,
+        _: &Executor<Context>,
+        _: &QueryTrail<HttpRequest, Walked>,
+    ) -> FieldResult<HttpRequest> {
+        Ok(HttpRequest {
+            method: "GET".to_string(),
+        })
+    }
+}
+
+pub struct Link {
+    href: String,
+}
+
+impl LinkFields for Link {
+    fn field_href(&self, _: &Executor<Context>) -> FieldResult<&String> {
+        Ok(&self.href)
+    }
+}
+
+pub struct HttpRequest {
+    method: String,
+}
+
+impl HttpRequestFields for HttpRequest {
+    fn field_method(&self, _: &Executor<Context>) -> FieldResult<&String> {
+        Ok(&self.method)
+    }
+}
+
+type Context = ();
+
+fn schema() -> Schema {
+    Schema::new(
+        Query,
+        juniper::EmptyMutation::new(),
+        juniper::EmptySubscription::new(),
+    )
+}
+
+#[test]
+fn test_served_schema_keeps_graphql_names() {
+    assert_schema_matches(&schema(), SCHEMA_INFO.sdl);
+}
+
+#[test]
+fn test_querying_renamed_types_and_fields() {
+    let value = run_query(
+        r#"query {
+            userID(where: { nameContains: "https://example.com" }) {
+                __typename
+                href
+            }
+            color
+            lastHTTPRequest {
+                __typename
+                method
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "userID": {
+                "__typename": "URL",
+                "href": "https://example.com",
+            },
+            "color": "RED",
+            "lastHTTPRequest": {
+                "__typename": "HTTPRequest",
+                "method": "GET",
+            },
+        })
+    );
+}
+
+fn run_query(query: &str) -> Value {
+    let ctx = ();
+
+    let (res, _errors) =
+        juniper::execute_sync(query, None, &schema(), &Variables::new(), &ctx).unwrap();
+
+    let json = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    println!("--- <json> -----------------");
+    println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    println!("--- </json> -----------------");
+    json
+}
@@ -0,0 +1,120 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+use juniper::{EmptyMutation, EmptySubscription, Executor, FieldResult, ID};
+use juniper_from_schema::{
+    assert_schema_matches, diff_schema, graphql_schema_from_file, SchemaDifference,
+};
+
+graphql_schema_from_file!("tests/schemas/schema_diff.graphql");
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_all_posts(
+        &self,
+        _: &Executor<Context>,
+        _: &QueryTrail<'_, Post, Walked>,
+        first: i32,
+        filter: Option<PostFilter>,
+    ) -> FieldResult<Vec<Post>> {
+        unimplemented!()
+    }
+
+    fn field_node(
+        &self,
+        _: &Executor<Context>,
+        _: &QueryTrail<'_, Node, Walked>,
+        id: ID,
+    ) -> FieldResult<Option<Node>> {
+        unimplemented!()
+    }
+
+    fn field_old_field(&self, _: &Executor<Context>) -> FieldResult<Option<i32>> {
+        unimplemented!()
+    }
+}
+
+pub struct Post {
+    id: ID,
+    status: Status,
+}
+
+impl PostFields for Post {
+    fn field_id(&self, _: &Executor<Context>) -> FieldResult<&ID> {
+        Ok(&self.id)
+    }
+
+    fn field_status(&self, _: &Executor<Context>) -> FieldResult<&Status> {
+        Ok(&self.status)
+    }
+}
+
+pub struct Context;
+
+impl juniper::Context for Context {}
+
+fn schema() -> Schema {
+    Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+#[test]
+fn test_generated_schema_matches_source() {
+    let diff = diff_schema(&schema(), SCHEMA_INFO.sdl).without_default_values();
+    assert!(diff.is_empty(), "{}", diff);
+}
+
+#[test]
+fn test_default_argument_values_are_reported() {
+    let diff = diff_schema(&schema(), SCHEMA_INFO.sdl);
+
+    assert_eq!(
+        diff.differences,
+        vec![SchemaDifference {
+            path: "Query.allPosts(first:).default".to_string(),
+            expected: Some("10".to_string()),
+            actual: None,
+        }]
+    );
+    assert!(diff.differences[0].is_default_value());
+}
+
+#[test]
+fn test_assert_schema_matches() {
+    let sdl = SCHEMA_INFO.sdl.replace("first: Int = 10", "first: Int");
+    assert_ne!(sdl, SCHEMA_INFO.sdl);
+
+    assert_schema_matches(&schema(), &sdl);
+}
+
+#[test]
+#[should_panic(expected = "Query.allPosts(first:).default: `10` is missing")]
+fn test_assert_schema_matches_panics_on_differences() {
+    assert_schema_matches(&schema(), SCHEMA_INFO.sdl);
+}
+
+#[test]
+fn test_differences_are_reported() {
+    let sdl = SCHEMA_INFO
+        .sdl
+        .replace("The current status", "The status")
+        .replace("DRAFT", "DRAFT UNPUBLISHED");
+    assert_ne!(sdl, SCHEMA_INFO.sdl);
+
+    let diff = diff_schema(&schema(), &sdl).without_default_values();
+
+    assert_eq!(
+        diff.differences,
+        vec![
+            SchemaDifference {
+                path: "Post.status.description".to_string(),
+                expected: Some("The status".to_string()),
+                actual: Some("The current status".to_string()),
+            },
+            SchemaDifference {
+                path: "Status.UNPUBLISHED".to_string(),
+                expected: Some("value".to_string()),
+                actual: None,
+            },
+        ]
+    );
+}
@@ -0,0 +1,125 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use juniper::{Executor, FieldResult, ID};
+use juniper_from_schema::{graphql_schema, Ownership, TypeKind};
+
+graphql_schema! {
+    "A blog post"
+    type Post {
+        id: ID!
+        title: String! @juniper(ownership: "owned", infallible: true)
+    }
+
+    input PostFilter {
+        title: String
+    }
+
+    type Query {
+        allPosts(first: Int = 10, filter: PostFilter): [Post!]! @juniper(ownership: "owned")
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_all_posts(
+        &self,
+        _: &Executor<Context>,
+        _: &QueryTrail<'_, Post, Walked>,
+        first: i32,
+        filter: Option<PostFilter>,
+    ) -> FieldResult<Vec<Post>> {
+        unimplemented!()
+    }
+}
+
+pub struct Post;
+
+impl PostFields for Post {
+    fn field_id(&self, _: &Executor<Context>) -> FieldResult<&ID> {
+        unimplemented!()
+    }
+
+    fn field_title(&self, _: &Executor<Context>) -> String {
+        unimplemented!()
+    }
+}
+
+type Context = ();
+
+#[test]
+fn test_sdl() {
+    assert!(SCHEMA_INFO.sdl.contains("allPosts"));
+}
+
+#[test]
+fn test_types() {
+    let names = SCHEMA_INFO
+        .types
+        .iter()
+        .map(|type_info| type_info.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Post", "PostFilter", "Query"]);
+    assert!(SCHEMA_INFO.type_info("Missing").is_none());
+
+    let post = SCHEMA_INFO.type_info("Post").unwrap();
+    assert_eq!(post.kind, TypeKind::Object);
+    assert_eq!(post.description, Some("A blog post"));
+    assert!(post.input_fields.is_empty());
+}
+
+#[test]
+fn test_fields() {
+    let post = SCHEMA_INFO.type_info("Post").unwrap();
+
+    let id = post.field("id").unwrap();
+    assert_eq!(id.rust_name, "field_id");
+    assert_eq!(id.field_type, "ID!");
+    assert_eq!(id.ownership, Ownership::Borrowed);
+    assert!(!id.infallible);
+
+    let title = post.field("title").unwrap();
+    assert_eq!(title.rust_name, "field_title");
+    assert_eq!(title.field_type, "String!");
+    assert_eq!(title.ownership, Ownership::Owned);
+    assert!(title.infallible);
+    assert!(!title.is_async);
+
+    let all_posts = SCHEMA_INFO
+        .type_info("Query")
+        .unwrap()
+        .field("allPosts")
+        .unwrap();
+    assert_eq!(all_posts.rust_name, "field_all_posts");
+    assert_eq!(all_posts.field_type, "[Post!]!");
+}
+
+#[test]
+fn test_arguments_and_input_fields() {
+    let all_posts = SCHEMA_INFO
+        .type_info("Query")
+        .unwrap()
+        .field("allPosts")
+        .unwrap();
+
+    let first = all_posts.argument("first").unwrap();
+    assert_eq!(first.value_type, "Int");
+    assert_eq!(first.default_value, Some("10"));
+
+    let filter = all_posts.argument("filter").unwrap();
+    assert_eq!(filter.value_type, "PostFilter");
+    assert_eq!(filter.default_value, None);
+
+    let post_filter = SCHEMA_INFO.type_info("PostFilter").unwrap();
+    assert_eq!(post_filter.kind, TypeKind::InputObject);
+    assert!(post_filter.fields.is_empty());
+
+    let title = post_filter.input_field("title").unwrap();
+    assert_eq!(title.rust_name, "title");
+    assert_eq!(title.value_type, "String");
+}
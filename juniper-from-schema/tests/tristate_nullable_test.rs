@@ -0,0 +1,202 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use assert_json_diff::assert_json_include;
+use juniper::{Executor, FieldResult, Nullable, Variables};
+use juniper_from_schema::graphql_schema;
+use serde_json::{self, json, Value};
+
+graphql_schema! {
+    type Query {
+        updateUser(
+            input: UpdateUser!
+            nickname: String @juniper(nullable_kind: "tristate")
+        ): String! @juniper(ownership: "owned")
+
+        user: User! @juniper(ownership: "owned")
+    }
+
+    type User {
+        fromQueryTrail: String! @juniper(ownership: "owned")
+
+        posts(
+            before: String @juniper(nullable_kind: "tristate")
+            input: UpdateUser
+        ): String! @juniper(ownership: "owned")
+    }
+
+    input UpdateUser {
+        bio: String @juniper(nullable_kind: "tristate")
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_update_user(
+        &self,
+        _: &Executor<Context>,
+        input: UpdateUser,
+        nickname: Nullable<String>,
+    ) -> FieldResult<String> {
+        Ok(describe(&[input.bio, nickname]))
+    }
+
+    fn field_user(
+        &self,
+        _: &Executor<Context>,
+        trail: &QueryTrail<User, Walked>,
+    ) -> FieldResult<User> {
+        let args = trail.posts_args();
+        let bio = args
+            .input()
+            .map(|input| input.bio)
+            .unwrap_or(Nullable::ImplicitNull);
+
+        Ok(User {
+            from_query_trail: describe(&[args.before(), bio]),
+        })
+    }
+}
+
+pub struct User {
+    from_query_trail: String,
+}
+
+impl UserFields for User {
+    fn field_from_query_trail(&self, _: &Executor<Context>) -> FieldResult<String> {
+        Ok(self.from_query_trail.clone())
+    }
+
+    fn field_posts(
+        &self,
+        _: &Executor<Context>,
+        before: Nullable<String>,
+        input: Option<UpdateUser>,
+    ) -> FieldResult<String> {
+        let bio = input
+            .map(|input| input.bio)
+            .unwrap_or(Nullable::ImplicitNull);
+
+        Ok(describe(&[before, bio]))
+    }
+}
+
+fn describe(values: &[Nullable<String>]) -> String {
+    values
+        .iter()
+        .map(|value| match value {
+            Nullable::ImplicitNull => "implicit null".to_string(),
+            Nullable::ExplicitNull => "explicit null".to_string(),
+            Nullable::Some(value) => value.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+type Context = ();
+
+#[test]
+fn test_arguments() {
+    let value = run_query(r#"query { updateUser(input: { bio: null }, nickname: null) }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({ "updateUser": "explicit null, explicit null" })
+    );
+
+    let value = run_query(r#"query { updateUser(input: {}) }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({ "updateUser": "implicit null, implicit null" })
+    );
+
+    let value = run_query(r#"query { updateUser(input: { bio: "bio" }, nickname: "nickname") }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({ "updateUser": "bio, nickname" })
+    );
+}
+
+#[test]
+fn test_query_trail_arguments() {
+    let value = run_query(
+        r#"query {
+            user {
+                fromQueryTrail
+                posts(before: null, input: { bio: null })
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "user": {
+                "fromQueryTrail": "explicit null, explicit null",
+                "posts": "explicit null, explicit null",
+            }
+        })
+    );
+
+    let value = run_query(
+        r#"query {
+            user {
+                fromQueryTrail
+                posts(input: {})
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "user": {
+                "fromQueryTrail": "implicit null, implicit null",
+                "posts": "implicit null, implicit null",
+            }
+        })
+    );
+
+    let value = run_query(
+        r#"query {
+            user {
+                fromQueryTrail
+                posts(before: "cursor", input: { bio: "bio" })
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "user": {
+                "fromQueryTrail": "cursor, bio",
+                "posts": "cursor, bio",
+            }
+        })
+    );
+}
+
+fn run_query(query: &str) -> Value {
+    let ctx = ();
+
+    let (res, _errors) = juniper::execute_sync(
+        query,
+        None,
+        &Schema::new(
+            Query,
+            juniper::EmptyMutation::new(),
+            juniper::EmptySubscription::new(),
+        ),
+        &Variables::new(),
+        &ctx,
+    )
+    .unwrap();
+
+    let json = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    println!("--- <json> -----------------");
+    println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    println!("--- </json> -----------------");
+    json
+}